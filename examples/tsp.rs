@@ -1,13 +1,15 @@
 #[macro_use]
 extern crate genetic;
+extern crate rand;
 
-use genetic::{Algorithm, Permutation, Problem};
+use genetic::{Algorithm, Objective, Permutation, Problem};
 use genetic::crossover::*;
 use genetic::selection::*;
 use genetic::mutation::*;
 use genetic::reinsertion::*;
 use genetic::termination::*;
 use genetic::tracking::*;
+use rand::{SeedableRng, XorShiftRng};
 
 
 #[derive(Clone)]
@@ -84,8 +86,7 @@ fn main() {
                       City::new("Canterbury", 51.289406, 1.075802)];
 
     let tsp_fit = |c: &[usize]| {
-        let distance = travel_distance(&cities, c);
-        1.0 - distance / 10000.0
+        travel_distance(&cities, c)
     };
 
     let mut alg = genetic_algorithm!(
@@ -94,11 +95,13 @@ fn main() {
          crossover:   (EdgeRecombination::new(), rate: 0.8),
          mutation:    (Twors::new(),             rate: 0.1),
          reinsertion: Elitist::new(),
-         tracking:    BestSolution::new()
+         tracking:    BestSolution::new(),
+         objective:   Objective::Minimize,
+         rng:         XorShiftRng::from_seed([1, 2, 3, 4])
     );
 
     let problem = Permutation::from(0..cities.len());
-    let population = problem.generate_population(1000);
+    let population = problem.generate_population(1000, &mut XorShiftRng::from_seed([5, 6, 7, 8]));
     let tracking = alg.evolve(population, Iterations::new(400));
 
     print_path(&cities, &tracking.best().0);
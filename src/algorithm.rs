@@ -1,18 +1,36 @@
-use std::ops::Fn;
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Instant;
 
-use rand;
+use rand::Rng;
 
+use cancellation::Cancellation;
+use checkpoint::{Checkpoint, Reader, Writer, parse};
 use chromosome::Chromosome;
-use crossover::Crossover;
+use crossover::{Crossover, DynCrossover};
+use evaluation::Evaluate;
 use generation::Generation;
-use mutation::Mutation;
-use reinsertion::Reinsertion;
-use selection::Selection;
+use island::Island;
+use mutation::{DynMutation, Mutation};
+use objective::Objective;
+use observer::{Event, Flow, Kind, Observer};
+use random::Xorshift128;
+use reinsertion::{DynReinsertion, Reinsertion};
+use schedule::{Progress, Schedule};
+use selection::{DynSelection, Selection};
 use termination::Termination;
-use tracking::Tracking;
+use tracking::{DynTracking, Lineage, Origin, Tracking};
 use utility::RngExt;
 
-pub struct Algorithm<'a, T, C, F, M, R, S, TR> where F: 'a {
+// An algorithm whose operators are chosen at runtime. Every operator is boxed, so they can be swapped for
+// a different kind of operator in the middle of a run (see `set_selection`, `set_crossover`, etc.).
+pub type DynAlgorithm<'a, T, F> = Algorithm<'a, T, Box<DynCrossover<T>>, F, Box<DynMutation<T>>,
+                                            Box<DynReinsertion<T>>, Box<DynSelection<T>>,
+                                            Box<DynTracking<T>>>;
+
+pub struct Algorithm<'a, T, C, F, M, R, S, TR, G = Xorshift128> where F: 'a + ?Sized {
     generation: Generation<T>,
     crossover: C,
     fitness: &'a F,
@@ -22,12 +40,18 @@ pub struct Algorithm<'a, T, C, F, M, R, S, TR> where F: 'a {
     tracking: TR,
     mutation_rate: f64,
     crossover_rate: f64,
+    mutation_schedule: Option<Box<Schedule<T> + Send + 'a>>,
+    crossover_schedule: Option<Box<Schedule<T> + Send + 'a>>,
+    objective: Objective,
+    rng: G,
+    monitor: Monitor<'a, T>,
+    next_id: u64,
 }
 
-impl<'a, T, C, F, M, R, S, TR> Algorithm<'a, T, C, F, M, R, S, TR>
-    where T: Clone,
+impl<'a, T, C, F, M, R, S, TR> Algorithm<'a, T, C, F, M, R, S, TR, Xorshift128>
+    where T: Clone + PartialEq,
           C: Crossover<T>,
-          F: 'a + Fn(&[T]) -> f64,
+          F: 'a + Evaluate<T> + ?Sized,
           M: Mutation<T>,
           R: Reinsertion<T>,
           S: Selection<T>,
@@ -48,42 +72,506 @@ impl<'a, T, C, F, M, R, S, TR> Algorithm<'a, T, C, F, M, R, S, TR>
             selection: selection,
             mutation_rate: mutation_rate,
             crossover_rate: crossover_rate,
+            mutation_schedule: None,
+            crossover_schedule: None,
             tracking: tracking,
+            objective: Objective::default(),
+            rng: Xorshift128::new(),
+            monitor: Monitor::new(),
+            next_id: 1,
+        }
+    }
+}
+
+impl<'a, T, C, F, M, R, S, TR, G> Algorithm<'a, T, C, F, M, R, S, TR, G>
+    where T: Clone + PartialEq,
+          C: Crossover<T>,
+          F: 'a + Evaluate<T> + ?Sized,
+          M: Mutation<T>,
+          R: Reinsertion<T>,
+          S: Selection<T>,
+          TR: Tracking<T>,
+          G: Rng {
+
+    // Replaces the random number generator used by every operator. Passing in a seeded generator makes
+    // runs reproducible.
+    pub fn rng<H>(self, rng: H) -> Algorithm<'a, T, C, F, M, R, S, TR, H> where H: Rng {
+        Algorithm {
+            generation: self.generation,
+            crossover: self.crossover,
+            fitness: self.fitness,
+            mutation: self.mutation,
+            reinsertion: self.reinsertion,
+            selection: self.selection,
+            mutation_rate: self.mutation_rate,
+            crossover_rate: self.crossover_rate,
+            mutation_schedule: self.mutation_schedule,
+            crossover_schedule: self.crossover_schedule,
+            tracking: self.tracking,
+            objective: self.objective,
+            rng: rng,
+            monitor: self.monitor,
+            next_id: self.next_id,
         }
     }
 
+    // Sets whether the fitness function should be maximized (the default) or minimized.
+    pub fn objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    // Adds an observer, which is notified of every event of the run (see `observer::Kind`). Observers and
+    // schedules have to be `Send`, so the algorithm can still be moved to another thread (e.g. to be
+    // cancelled from the outside, see `Cancellation`).
+    pub fn observer<O>(mut self, observer: O) -> Self where O: Observer<T> + Send + 'a {
+        self.monitor.observers.push(Box::new(observer));
+        self
+    }
+
+    // Lets the observers know whenever the best fitness didn't improve for the given number of generations
+    // in a row. The event is repeated after every further `generations` generations without improvement.
+    pub fn stagnation(mut self, generations: usize) -> Self {
+        self.monitor.stagnation = generations;
+        self
+    }
+
+    // Adapts the mutation rate after every generation, starting from the rate passed to `new`.
+    pub fn mutation_schedule<SC>(mut self, schedule: SC) -> Self where SC: Schedule<T> + Send + 'a {
+        self.mutation_schedule = Some(Box::new(schedule));
+        self
+    }
+
+    // Adapts the crossover rate after every generation, starting from the rate passed to `new`.
+    pub fn crossover_schedule<SC>(mut self, schedule: SC) -> Self where SC: Schedule<T> + Send + 'a {
+        self.crossover_schedule = Some(Box::new(schedule));
+        self
+    }
+
+    // Makes `evolve` and `resume` return after the current generation once the handle was cancelled.
+    pub fn cancellation(mut self, cancellation: Cancellation) -> Self {
+        self.monitor.cancellation = Some(cancellation);
+        self
+    }
+
+    // The operators and rates can be replaced between two generations, e.g. from a loop around `step`.
+    pub fn set_selection(&mut self, selection: S) {
+        self.selection = selection;
+    }
+
+    pub fn set_crossover(&mut self, crossover: C) {
+        self.crossover = crossover;
+    }
+
+    pub fn set_crossover_rate(&mut self, crossover_rate: f64) {
+        self.crossover_rate = crossover_rate;
+    }
+
+    pub fn set_mutation(&mut self, mutation: M) {
+        self.mutation = mutation;
+    }
+
+    pub fn set_mutation_rate(&mut self, mutation_rate: f64) {
+        self.mutation_rate = mutation_rate;
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn crossover_rate(&self) -> f64 {
+        self.crossover_rate
+    }
+
+    pub fn set_reinsertion(&mut self, reinsertion: R) {
+        self.reinsertion = reinsertion;
+    }
+
     pub fn evolve<U, I, Term>(&mut self, generation: I, mut termination: Term) -> &TR
         where U: IntoIterator<Item = T>,
               I: IntoIterator<Item = U>,
-              Term: Termination {
+              Term: Termination<T> {
+
+        self.initialize(generation);
+        while !self.monitor.is_stopped() && !termination.reached(&self.generation) {
+            self.step();
+        }
+        self.finish();
+
+        &self.tracking
+    }
+
+    // Evaluates the initial population, after which the algorithm can be advanced one generation at a time
+    // with `step`.
+    pub fn initialize<U, I>(&mut self, generation: I)
+        where U: IntoIterator<Item = T>,
+              I: IntoIterator<Item = U> {
 
-        let mut rng = rand::thread_rng();
-        self.generation = Generation::new(generation, self.fitness);
+        self.generation = Generation::new(generation, self.fitness, self.objective);
+        if self.tracking.lineage() {
+            for i in 0..self.generation.size() {
+                let id = self.next_id();
+                let lineage = Lineage { id: id, parents: Vec::new(), origin: Origin::Initial };
+                self.generation[i].lineage = Some(lineage);
+            }
+        }
         self.tracking.register(&self.generation);
+        self.monitor.reset(&self.generation);
+        self.monitor.notify(Kind::RunStarted, &self.generation);
+    }
 
-        while !termination.reached(&self.generation) {
-            let offspring = self.selection
-                .select(&self.generation, &self.crossover)
-                .chunks(self.crossover.parents())
-                .map(|parents| {
-                    if rng.happens(self.crossover_rate) {
-                        self.crossover.cross(&parents)
+    pub fn step(&mut self) -> &Generation<T> {
+        if self.generation.size() == 0 {
+            panic!("The algorithm has to be initialized before it can be advanced");
+        }
+        let index = self.monitor.index + 1;
+        self.monitor.notify_at(Kind::GenerationStarted, index, &self.generation);
+
+        let selected = self.selection.select(&self.generation, &self.crossover, &mut self.rng);
+
+        let lineage = self.tracking.lineage();
+        let objective = self.objective;
+        let mut offspring = Vec::with_capacity(selected.len());
+        // The fitness of the best parent of every new child, to count the successful ones.
+        let mut parent_fitness = Vec::with_capacity(selected.len());
+        for parents in selected.chunks(self.crossover.parents()) {
+            if self.rng.happens(self.crossover_rate) {
+                let best = parents.iter().skip(1).fold(parents[0].fitness, |best, p| {
+                    if objective.is_better(p.fitness, best) { p.fitness } else { best }
+                });
+                for child in self.crossover.cross(parents, &mut self.rng) {
+                    // The unmutated child is only kept to tell whether the mutation contributed to it.
+                    let crossed = if lineage { Some(child.clone()) } else { None };
+                    let child = self.mutation.mutate(child, self.mutation_rate, &mut self.rng);
+                    let mut chromosome = Chromosome::from(child);
+                    if let Some(crossed) = crossed {
+                        let mutation = if crossed[..] != chromosome[..] {
+                            Some(self.mutation.name())
+                        } else {
+                            None
+                        };
+                        let crossover = self.crossover.name();
+                        let origin = Origin::Crossover { crossover: crossover, mutation: mutation };
+                        let ids = parents.iter().filter_map(|p| p.lineage.as_ref().map(|l| l.id)).collect();
+                        self.descend(&mut chromosome, ids, origin);
+                    }
+                    parent_fitness.push((offspring.len(), best));
+                    offspring.push(chromosome);
+                }
+            } else {
+                // Parents that are passed on keep their fitness unless the mutation changes them, so
+                // they don't have to be evaluated again.
+                for parent in parents {
+                    let genes = parent.genes().to_vec();
+                    let child = self.mutation.mutate(genes, self.mutation_rate, &mut self.rng);
+                    if child[..] == parent[..] {
+                        offspring.push(parent.clone());
                     } else {
-                        parents.iter().map(|x| (*x).clone()).collect()
+                        let mut chromosome = Chromosome::from(child);
+                        if lineage {
+                            let origin = Origin::Mutation { mutation: self.mutation.name() };
+                            let ids = parent.lineage.iter().map(|l| l.id).collect();
+                            self.descend(&mut chromosome, ids, origin);
+                        }
+                        parent_fitness.push((offspring.len(), parent.fitness));
+                        offspring.push(chromosome);
                     }
-                })
-                .flat_map(|offspring| offspring.into_iter())
-                .map(|child| self.mutation.mutate(child, self.mutation_rate))
-                .map(Chromosome::from)
-                .collect();
+                }
+            }
+        }
 
-            self.generation = self.reinsertion.reinsert(&self.generation, offspring);
-            self.generation.reevaluate(&self.fitness);
-            self.tracking.register(&self.generation);
+        // With a schedule the offspring are evaluated before the reinsertion, so it can tell how many of them
+        // improved on their parents. Otherwise they're left to the reinsertion, which may not need them all.
+        let mut evaluations = self.generation.evaluations();
+        let mut successes = 0;
+        if self.mutation_schedule.is_some() || self.crossover_schedule.is_some() {
+            evaluations += self.fitness.evaluate(&mut offspring);
+            successes = parent_fitness.iter()
+                .filter(|&&(i, fitness)| objective.is_better(offspring[i].fitness, fitness))
+                .count();
         }
 
+        let children = if lineage {
+            offspring.iter().filter_map(|c| c.lineage.as_ref().map(|l| l.id)).collect()
+        } else {
+            HashSet::new()
+        };
+        self.generation = self.reinsertion.reinsert(&self.generation, offspring, &mut self.rng);
+        if lineage {
+            self.carry_over(&children);
+        }
+        self.generation.add_evaluations(evaluations);
+        self.generation.reevaluate(self.fitness, self.objective);
+        self.tracking.register(&self.generation);
+        self.monitor.advance(&self.generation);
+
+        let progress = Progress {
+            index: self.monitor.index,
+            generation: &self.generation,
+            offspring: parent_fitness.len(),
+            successes: successes,
+        };
+        if let Some(ref mut schedule) = self.mutation_schedule {
+            self.mutation_rate = schedule.rate(self.mutation_rate, &progress);
+        }
+        if let Some(ref mut schedule) = self.crossover_schedule {
+            self.crossover_rate = schedule.rate(self.crossover_rate, &progress);
+        }
+        &self.generation
+    }
+
+    // Lets the observers know that the run ended. Called by `evolve` and `resume`, so it's only needed when
+    // the algorithm is advanced with `step`.
+    pub fn finish(&mut self) {
+        self.monitor.notify(Kind::RunFinished, &self.generation);
+    }
+
+    // Whether an observer asked to stop the run (which is reset by `initialize`), or the run was cancelled.
+    pub fn is_stopped(&self) -> bool {
+        self.monitor.is_stopped()
+    }
+
+    pub fn generation(&self) -> &Generation<T> {
+        &self.generation
+    }
+
+    pub fn tracking(&self) -> &TR {
         &self.tracking
     }
+
+    // Lets chromosomes from outside the algorithm (e.g. from another population) replace the worst ones of
+    // the current generation. The migrants have to be evaluated with the same fitness function already.
+    // Their lineage belongs to another algorithm, so they get a new one without parents.
+    pub fn immigrate(&mut self, mut migrants: Vec<Chromosome<T>>) {
+        let lineage = self.tracking.lineage();
+        for migrant in &mut migrants {
+            migrant.lineage = None;
+            if lineage {
+                self.descend(migrant, Vec::new(), Origin::Migration);
+            }
+        }
+        self.generation.replace_worst(migrants);
+    }
+
+    fn next_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    fn descend(&mut self, chromosome: &mut Chromosome<T>, parents: Vec<u64>, origin: Origin) {
+        let id = self.next_id();
+        chromosome.lineage = Some(Lineage { id: id, parents: parents, origin: origin });
+    }
+
+    // Chromosomes of the new generation that aren't one of the offspring were carried over from the parents
+    // by the reinsertion, so they're recorded as copies of them.
+    fn carry_over(&mut self, offspring: &HashSet<u64>) {
+        for i in 0..self.generation.size() {
+            let parent = match self.generation[i].lineage {
+                Some(ref lineage) if !offspring.contains(&lineage.id) => lineage.id,
+                _ => continue,
+            };
+            let lineage = Lineage { id: self.next_id(), parents: vec![parent], origin: Origin::Elitism };
+            self.generation[i].lineage = Some(lineage);
+        }
+    }
+}
+
+impl<'a, T, C, F, M, R, S, TR, G> Algorithm<'a, T, C, F, M, R, S, TR, G>
+    where T: Clone + PartialEq + ToString + FromStr,
+          C: Crossover<T>,
+          F: 'a + Evaluate<T> + ?Sized,
+          M: Mutation<T>,
+          R: Reinsertion<T>,
+          S: Selection<T> + Checkpoint,
+          TR: Tracking<T> + Checkpoint,
+          G: Rng + Checkpoint {
+
+    // Saves the state of the random number generator, the current generation, the progress of the run (its
+    // index and how long the best fitness hasn't improved), the rates, the selection, the termination
+    // condition and the tracker, so the run continues exactly where it stopped.
+    pub fn save<P, Term>(&self, path: P, termination: &Term) -> io::Result<()>
+        where P: AsRef<Path>,
+              Term: Checkpoint {
+
+        let mut writer = Writer::new();
+        self.rng.save(&mut writer);
+        self.generation.save(&mut writer);
+        self.monitor.save(&mut writer);
+        writer.write("rates", vec![self.mutation_rate.to_string(), self.crossover_rate.to_string()]);
+        self.selection.save(&mut writer);
+        termination.save(&mut writer);
+        self.tracking.save(&mut writer);
+        writer.save(path)
+    }
+
+    // Restores a checkpoint written by `save`, after which the algorithm can be advanced with `step`. The
+    // algorithm has to be built with the same operators and fitness function as the one that was saved.
+    pub fn restore<P, Term>(&mut self, path: P, termination: &mut Term) -> io::Result<()>
+        where P: AsRef<Path>,
+              Term: Checkpoint {
+
+        let mut reader = try!(Reader::open(path));
+        try!(self.rng.restore(&mut reader));
+        try!(self.generation.restore(&mut reader));
+        self.monitor.reset(&self.generation);
+        try!(self.monitor.restore(&mut reader));
+        let rates = try!(reader.read("rates", Some(2)));
+        self.mutation_rate = try!(parse(&rates[0]));
+        self.crossover_rate = try!(parse(&rates[1]));
+        try!(self.selection.restore(&mut reader));
+        try!(termination.restore(&mut reader));
+        self.tracking.restore(&mut reader)
+    }
+
+    // Like `evolve`, but continues from a checkpoint instead of starting with a new population.
+    pub fn resume<P, Term>(&mut self, path: P, mut termination: Term) -> io::Result<&TR>
+        where P: AsRef<Path>,
+              Term: Termination<T> + Checkpoint {
+
+        try!(self.restore(path, &mut termination));
+        self.monitor.notify(Kind::RunStarted, &self.generation);
+        while !self.monitor.is_stopped() && !termination.reached(&self.generation) {
+            self.step();
+        }
+        self.finish();
+
+        Ok(&self.tracking)
+    }
+}
+
+impl<'a, T, C, F, M, R, S, TR, G> Island<T> for Algorithm<'a, T, C, F, M, R, S, TR, G>
+    where T: Clone + PartialEq,
+          C: Crossover<T>,
+          F: 'a + Evaluate<T> + ?Sized,
+          M: Mutation<T>,
+          R: Reinsertion<T>,
+          S: Selection<T>,
+          TR: Tracking<T>,
+          G: Rng {
+
+    fn initialize(&mut self, population: Vec<Vec<T>>) {
+        Algorithm::initialize(self, population);
+    }
+
+    fn advance(&mut self) {
+        self.step();
+    }
+
+    fn generation(&self) -> &Generation<T> {
+        &self.generation
+    }
+
+    fn immigrate(&mut self, migrants: Vec<Chromosome<T>>) {
+        Algorithm::immigrate(self, migrants);
+    }
+}
+
+// Keeps track of the progress of a run for the observers. The generation index, the best fitness and the
+// stagnation count are part of checkpoints, only the elapsed time starts over after restoring.
+struct Monitor<'a, T> {
+    observers: Vec<Box<Observer<T> + Send + 'a>>,
+    cancellation: Option<Cancellation>,
+    stagnation: usize,
+    index: usize,
+    start: Instant,
+    best: Option<f64>,
+    unimproved: usize,
+    stopped: bool,
+}
+
+impl<'a, T> Monitor<'a, T> {
+    fn new() -> Self {
+        Monitor {
+            observers: Vec::new(),
+            cancellation: None,
+            stagnation: 0,
+            index: 0,
+            start: Instant::now(),
+            best: None,
+            unimproved: 0,
+            stopped: false,
+        }
+    }
+
+    fn reset(&mut self, generation: &Generation<T>) {
+        self.index = 0;
+        self.start = Instant::now();
+        self.best = Some(generation.best().fitness);
+        self.unimproved = 0;
+        self.stopped = false;
+    }
+
+    fn is_stopped(&self) -> bool {
+        self.stopped || self.cancellation.as_ref().map_or(false, |c| c.is_cancelled())
+    }
+
+    fn advance(&mut self, generation: &Generation<T>) {
+        self.index += 1;
+        self.notify(Kind::GenerationFinished, generation);
+
+        let fitness = generation.best().fitness;
+        let improved = match self.best {
+            Some(best) => generation.objective().is_better(fitness, best),
+            None => true,
+        };
+        if improved {
+            self.best = Some(fitness);
+            self.unimproved = 0;
+            self.notify(Kind::NewBest, generation);
+        } else {
+            self.unimproved += 1;
+            if self.stagnation > 0 && self.unimproved % self.stagnation == 0 {
+                let unimproved = self.unimproved;
+                self.notify(Kind::Stagnation(unimproved), generation);
+            }
+        }
+    }
+
+    fn notify(&mut self, kind: Kind, generation: &Generation<T>) {
+        let index = self.index;
+        self.notify_at(kind, index, generation);
+    }
+
+    fn notify_at(&mut self, kind: Kind, index: usize, generation: &Generation<T>) {
+        if self.observers.is_empty() {
+            return;
+        }
+
+        let event = Event {
+            kind: kind,
+            index: index,
+            elapsed: self.start.elapsed(),
+            generation: generation,
+        };
+        for observer in &mut self.observers {
+            if observer.notify(&event) == Flow::Stop {
+                self.stopped = true;
+            }
+        }
+    }
+}
+
+impl<'a, T> Checkpoint for Monitor<'a, T> {
+    fn save(&self, writer: &mut Writer) {
+        let mut values = vec![self.index.to_string(), self.unimproved.to_string()];
+        values.extend(self.best.map(|best| best.to_string()));
+        writer.write("progress", values);
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("progress", None));
+        if values.len() < 2 || values.len() > 3 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Invalid progress in checkpoint"));
+        }
+        self.index = try!(parse(&values[0]));
+        self.unimproved = try!(parse(&values[1]));
+        if let Some(best) = values.get(2) {
+            self.best = Some(try!(parse(best)));
+        }
+        Ok(())
+    }
 }
 
 #[macro_export]
@@ -93,7 +581,8 @@ macro_rules! genetic_algorithm {
      crossover:  ($crossover:   expr, rate: $c_rate: expr),
      mutation:   ($mutation :   expr, rate: $m_rate: expr),
      reinsertion: $reinsertion: expr,
-     tracking:    $tracking: expr) => {{
+     tracking:    $tracking: expr
+     $(, $option: ident: $value: expr)*) => {{
         use genetic::algorithm::Algorithm;
         Algorithm::new( $fitness,
                         $selection,
@@ -101,5 +590,153 @@ macro_rules! genetic_algorithm {
                        ($mutation,  $m_rate),
                         $reinsertion,
                         $tracking)
+            $(.$option($value))*
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use rand::{SeedableRng, XorShiftRng};
+
+    use algorithm::{Algorithm, DynAlgorithm};
+    use crossover::{DynCrossover, Order1, PartiallyMapped};
+    use evaluation::Evaluate;
+    use mutation::{Shuffle, Twors};
+    use observer::{Event, Flow, Kind};
+    use problem::{Problem, Permutation};
+    use reinsertion::Elitist;
+    use schedule::LinearDecay;
+    use selection::{Roulette, Tournament};
+    use termination::Iterations;
+    use tracking::BestSolution;
+    use utility::weighted_sum;
+
+    fn seeded_run(seed: [u32; 4]) -> (Vec<usize>, f64) {
+        let mut rng = XorShiftRng::from_seed(seed);
+        let population = Permutation::from(0..20usize).generate_population(50, &mut rng);
+        let mut algorithm = test_algorithm!(&weighted_sum, BestSolution::new()).rng(rng);
+        let best = algorithm.evolve(population, Iterations::new(30)).best();
+        best
+    }
+
+    #[test]
+    fn restore_keeps_the_progress() {
+        let fitness = |genes: &[usize]| genes[0] as f64;
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let population = Permutation::from(0..10usize).generate_population(20, &mut rng);
+        let algorithm = || test_algorithm!(&fitness, BestSolution::new());
+        let path = env::temp_dir().join(format!("genetic-progress-test-{}", process::id()));
+
+        let mut original = algorithm();
+        original.initialize(population);
+        for _ in 0..20 {
+            original.step();
+        }
+        original.save(&path, &Iterations::new(0)).unwrap();
+        let mut restored = algorithm();
+        restored.restore(&path, &mut Iterations::new(0)).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(restored.monitor.index == 20);
+        assert!(restored.monitor.unimproved == original.monitor.unimproved);
+        assert!(restored.monitor.best == original.monitor.best);
+    }
+
+    #[test]
+    fn step_advances_one_generation() {
+        let fitness = |genes: &[usize]| genes[0] as f64;
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let population = Permutation::from(0..10usize).generate_population(20, &mut rng);
+        let mut algorithm = test_algorithm!(&fitness, BestSolution::new());
+
+        algorithm.initialize(population);
+        let initial = algorithm.generation().best().fitness;
+        for _ in 0..5 {
+            assert!(algorithm.step().size() == 20);
+        }
+
+        // The generations are elitist, so the best solution can only improve.
+        assert!(algorithm.generation().best().fitness >= initial);
+        assert!(algorithm.tracking().best().1 == algorithm.generation().best().fitness);
+    }
+
+    #[test]
+    fn swap_boxed_operators() {
+        let fitness = |genes: &[usize]| genes[0] as f64;
+        let fitness: &Evaluate<usize> = &fitness;
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let population = Permutation::from(0..10usize).generate_population(20, &mut rng);
+
+        let mut crossovers: Vec<Box<DynCrossover<usize>>> = vec![Box::new(Order1::new()),
+                                                                 Box::new(PartiallyMapped::new())];
+        let mut algorithm: DynAlgorithm<usize, Evaluate<usize>> =
+            Algorithm::new(fitness,
+                           Box::new(Tournament::new(0.9, 3)),
+                           (crossovers.remove(0), 0.8),
+                           (Box::new(Twors::new()), 0.2),
+                           Box::new(Elitist::new()),
+                           Box::new(BestSolution::new()));
+
+        algorithm.initialize(population);
+        for _ in 0..5 {
+            algorithm.step();
+        }
+
+        algorithm.set_selection(Box::new(Roulette::new(0.9)));
+        algorithm.set_crossover(crossovers.remove(0));
+        algorithm.set_mutation(Box::new(Shuffle::new()));
+        for _ in 0..5 {
+            assert!(algorithm.step().size() == 20);
+        }
+    }
+
+    #[test]
+    fn seeded_runs_are_reproducible() {
+        let first = seeded_run([1, 2, 3, 4]);
+        let second = seeded_run([1, 2, 3, 4]);
+        assert!(first.0 == second.0);
+        assert!(first.1 == second.1);
+    }
+
+    #[test]
+    fn observers_can_stop_the_run() {
+        let fitness = |genes: &[usize]| genes[0] as f64;
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let population = Permutation::from(0..10usize).generate_population(20, &mut rng);
+
+        let mut events = Vec::new();
+        {
+            let observer = |event: &Event<usize>| {
+                events.push((event.kind, event.index));
+                if event.kind == Kind::GenerationFinished && event.index == 3 {
+                    Flow::Stop
+                } else {
+                    Flow::Continue
+                }
+            };
+            let mut algorithm = test_algorithm!(&fitness, BestSolution::new()).observer(observer);
+            algorithm.evolve(population, Iterations::new(100));
+            assert!(algorithm.is_stopped());
+        }
+
+        let kinds = events.iter().filter(|&&(kind, _)| kind != Kind::NewBest).cloned().collect::<Vec<_>>();
+        assert!(kinds == vec![(Kind::RunStarted, 0),
+                              (Kind::GenerationStarted, 1), (Kind::GenerationFinished, 1),
+                              (Kind::GenerationStarted, 2), (Kind::GenerationFinished, 2),
+                              (Kind::GenerationStarted, 3), (Kind::GenerationFinished, 3),
+                              (Kind::RunFinished, 3)]);
+    }
+
+    #[test]
+    fn algorithms_with_observers_and_schedules_are_send() {
+        fn assert_send<S>(_: &S) where S: Send {}
+
+        let fitness = |genes: &[usize]| genes[0] as f64;
+        let algorithm = test_algorithm!(&fitness, BestSolution::new())
+            .observer(|_: &Event<usize>| Flow::Continue)
+            .mutation_schedule(LinearDecay::new(0.2, 0.01, 10));
+        assert_send(&algorithm);
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use generation::Generation;
+use termination::Termination;
+
+// A handle that stops a run from another thread. Every clone shares the same flag, so one clone can be
+// passed to `Algorithm::cancellation` while another one is kept to call `cancel`. The algorithm checks the
+// flag between two generations, so a run that is cancelled stops cleanly after the current generation, with
+// the tracker and the generation intact.
+//
+// Once cancelled a handle stays cancelled. It's also a termination condition, e.g. to cancel the evolution
+// of an `island::Archipelago`.
+#[derive(Clone, Debug, Default)]
+pub struct Cancellation {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Cancellation {
+    pub fn new() -> Self {
+        Cancellation::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+impl<T> Termination<T> for Cancellation {
+    fn reached(&mut self, _: &Generation<T>) -> bool {
+        self.is_cancelled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use cancellation::Cancellation;
+    use observer::{Event, Flow};
+    use problem::{Problem, Permutation};
+    use random::Xorshift128;
+    use termination::Iterations;
+    use tracking::Statistics;
+
+    #[test]
+    fn cancelled_runs_keep_their_tracker() {
+        let fitness = |genes: &[usize]| genes[0] as f64;
+        let mut rng = Xorshift128::from_seed([1, 2, 3, 4]);
+        let population = Permutation::from(0..10usize).generate_population(20, &mut rng);
+
+        let cancellation = Cancellation::new();
+        let handle = cancellation.clone();
+        let observer = move |event: &Event<usize>| {
+            if event.index == 2 {
+                handle.cancel();
+            }
+            Flow::Continue
+        };
+        let mut algorithm = test_algorithm!(&fitness, Statistics::new())
+            .cancellation(cancellation.clone())
+            .observer(observer);
+
+        assert!(algorithm.evolve(population, Iterations::new(100)).records().len() == 3);
+        assert!(cancellation.is_cancelled() && algorithm.is_stopped());
+    }
+}
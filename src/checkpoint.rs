@@ -0,0 +1,259 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use chromosome::Chromosome;
+use pareto::ParetoRank;
+
+// The version of the checkpoint format, which is stored in the header of every checkpoint. Checkpoints with
+// a different version are rejected when restoring.
+pub const VERSION: u32 = 5;
+
+const HEADER: &'static str = "genetic-checkpoint";
+
+// A checkpoint is a plain text file, where every line starts with a key followed by whitespace separated
+// values. Genes are stored using their `ToString` and `FromStr` implementations, so their textual
+// representation may not contain any whitespace.
+pub trait Checkpoint {
+    fn save(&self, writer: &mut Writer);
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()>;
+}
+
+pub struct Writer {
+    lines: Vec<String>,
+}
+
+impl Writer {
+    pub fn new() -> Self {
+        Writer { lines: vec![format!("{} {}", HEADER, VERSION)] }
+    }
+
+    pub fn write(&mut self, key: &str, values: Vec<String>) {
+        let mut line = key.to_owned();
+        for value in values {
+            line.push(' ');
+            line.push_str(&value);
+        }
+        self.lines.push(line);
+    }
+
+    // Chromosomes are written as their fitness and the number of objectives, followed by the front, the
+    // crowding distance and the objective values if the chromosome has a Pareto rank, and the genes.
+    pub fn chromosome<T>(&mut self, key: &str, chromosome: &Chromosome<T>) where T: ToString {
+        let mut values = vec![chromosome.fitness.to_string()];
+        match chromosome.pareto {
+            Some(ref rank) => {
+                values.push(rank.objectives.len().to_string());
+                values.push(rank.front.to_string());
+                values.push(rank.crowding.to_string());
+                values.extend(rank.objectives.iter().map(|o| o.to_string()));
+            },
+            None => values.push("0".to_owned()),
+        }
+        values.extend(chromosome.genes().iter().map(|g| g.to_string()));
+        self.write(key, values);
+    }
+
+    // Writes the checkpoint to a temporary file first, so an interrupted save never destroys the previous
+    // checkpoint.
+    pub fn save<P>(&self, path: P) -> io::Result<()> where P: AsRef<Path> {
+        let path = path.as_ref();
+        let temporary = path.with_extension("tmp");
+        {
+            let mut file = BufWriter::new(try!(File::create(&temporary)));
+            for line in &self.lines {
+                try!(writeln!(file, "{}", line));
+            }
+            try!(file.flush());
+        }
+        ::std::fs::rename(&temporary, path)
+    }
+}
+
+pub struct Reader {
+    lines: Vec<Vec<String>>,
+    position: usize,
+}
+
+impl Reader {
+    pub fn open<P>(path: P) -> io::Result<Self> where P: AsRef<Path> {
+        let mut contents = String::new();
+        try!(try!(File::open(path)).read_to_string(&mut contents));
+        Reader::parse(&contents)
+    }
+
+    pub fn parse(contents: &str) -> io::Result<Self> {
+        let mut lines = contents.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.split_whitespace().map(|s| s.to_owned()).collect::<Vec<_>>());
+
+        match lines.next() {
+            Some(ref header) if header.len() == 2 && header[0] == HEADER => {
+                let version: u32 = try!(parse(&header[1]));
+                if version != VERSION {
+                    return Err(invalid(format!("Unsupported checkpoint version {} (expected {})",
+                                               version, VERSION)));
+                }
+            },
+            _ => return Err(invalid("Not a checkpoint file".to_owned())),
+        }
+
+        Ok(Reader {
+            lines: lines.collect(),
+            position: 0,
+        })
+    }
+
+    // Reads the values of the next line, which has to start with the given key. If `count` is given the
+    // line has to contain exactly that many values.
+    pub fn read(&mut self, key: &str, count: Option<usize>) -> io::Result<Vec<String>> {
+        let line = match self.lines.get(self.position) {
+            Some(line) => line,
+            None => return Err(invalid(format!("Unexpected end of checkpoint, expected `{}`", key))),
+        };
+        if line[0] != key {
+            return Err(invalid(format!("Expected `{}` in checkpoint, found `{}`", key, line[0])));
+        }
+        if let Some(count) = count {
+            if line.len() - 1 != count {
+                let message = format!("Expected {} values for `{}`, found {}", count, key, line.len() - 1);
+                return Err(invalid(message));
+            }
+        }
+
+        self.position += 1;
+        Ok(line[1..].to_vec())
+    }
+
+    pub fn chromosome<T>(&mut self, key: &str) -> io::Result<Chromosome<T>> where T: FromStr {
+        let values = try!(self.read(key, None));
+        if values.len() < 2 {
+            return Err(invalid(format!("Incomplete chromosome for `{}`", key)));
+        }
+
+        let objectives: usize = try!(parse(&values[1]));
+        let start = if objectives == 0 { 2 } else { 4 + objectives };
+        if values.len() < start {
+            return Err(invalid(format!("Incomplete chromosome for `{}`", key)));
+        }
+
+        let mut genes = Vec::with_capacity(values.len() - start);
+        for value in &values[start..] {
+            genes.push(try!(parse(value)));
+        }
+
+        let mut chromosome = Chromosome::new(genes, try!(parse(&values[0])));
+        if objectives > 0 {
+            let mut rank = ParetoRank {
+                objectives: Vec::with_capacity(objectives),
+                front: try!(parse(&values[2])),
+                crowding: try!(parse(&values[3])),
+            };
+            for value in &values[4..start] {
+                rank.objectives.push(try!(parse(value)));
+            }
+            chromosome.pareto = Some(rank);
+        }
+        Ok(chromosome)
+    }
+}
+
+pub fn parse<V>(value: &str) -> io::Result<V> where V: FromStr {
+    value.parse().map_err(|_| invalid(format!("Invalid value `{}` in checkpoint", value)))
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use checkpoint::{Reader, Writer};
+    use problem::{Problem, Permutation};
+    use rand::SeedableRng;
+    use random::Xorshift128;
+    use schedule::ExponentialDecay;
+    use selection::Boltzmann;
+    use termination::{FitnessConvergence, Termination};
+    use tracking::BestSolution;
+    use utility::weighted_sum;
+
+    #[test]
+    fn checkpoint_resumes_exactly() {
+        // The annealed temperature has to continue from where it was saved.
+        let selection = Boltzmann::annealed(0.9, 100.0, 0.5, 0.01);
+        let algorithm = || {
+            test_algorithm!(&weighted_sum, selection.clone(), BestSolution::new())
+                .rng(Xorshift128::from_seed([1, 2, 3, 4]))
+        };
+        let path = env::temp_dir().join(format!("genetic-checkpoint-test-{}", process::id()));
+
+        let mut rng = Xorshift128::from_seed([5, 6, 7, 8]);
+        let mut original = algorithm();
+        let mut termination = FitnessConvergence::new(1000, 1e-9);
+        original.initialize(Permutation::from(0..20usize).generate_population(30, &mut rng));
+        for _ in 0..10 {
+            termination.reached(original.step());
+        }
+        original.save(&path, &termination).unwrap();
+
+        let mut restored = algorithm();
+        let mut restored_termination = FitnessConvergence::new(1000, 1e-9);
+        restored.restore(&path, &mut restored_termination).unwrap();
+
+        for _ in 0..10 {
+            let first = termination.reached(original.step());
+            let second = restored_termination.reached(restored.step());
+            assert!(first == second);
+            assert!(original.generation().best().genes() == restored.generation().best().genes());
+        }
+        assert!(original.tracking().best() == restored.tracking().best());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn checkpoint_continues_schedules() {
+        let algorithm = || {
+            test_algorithm!(&weighted_sum, BestSolution::new())
+                .rng(Xorshift128::from_seed([1, 2, 3, 4]))
+                .mutation_schedule(ExponentialDecay::new(0.5, 0.1, 0.5))
+        };
+        let path = env::temp_dir().join(format!("genetic-checkpoint-schedule-test-{}", process::id()));
+
+        let mut rng = Xorshift128::from_seed([5, 6, 7, 8]);
+        let mut original = algorithm();
+        let termination = FitnessConvergence::new(1000, 1e-9);
+        original.initialize(Permutation::from(0..20usize).generate_population(30, &mut rng));
+        for _ in 0..3 {
+            original.step();
+        }
+        original.save(&path, &termination).unwrap();
+
+        let mut restored = algorithm();
+        let mut restored_termination = FitnessConvergence::new(1000, 1e-9);
+        restored.restore(&path, &mut restored_termination).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // The decay continues from the fourth generation instead of starting over at 0.5.
+        restored.step();
+        assert_approx_eq!(restored.mutation_rate(), 0.1 + 0.4 * 0.5f64.powi(4), 1e-9);
+        original.step();
+        assert_approx_eq!(restored.mutation_rate(), original.mutation_rate(), 1e-9);
+    }
+
+    #[test]
+    fn checkpoint_rejects_other_versions() {
+        assert!(Reader::parse("genetic-checkpoint 0\n").is_err());
+        assert!(Reader::parse("something else\n").is_err());
+
+        let mut writer = Writer::new();
+        writer.write("iterations", vec!["3".to_owned()]);
+        let mut reader = Reader::parse(&writer.lines.join("\n")).unwrap();
+        assert!(reader.read("generation", None).is_err());
+        assert!(reader.read("iterations", Some(1)).unwrap() == vec!["3".to_owned()]);
+    }
+}
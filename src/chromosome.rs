@@ -1,29 +1,64 @@
 use std::cmp::Ordering;
 
+use pareto::ParetoRank;
+use tracking::Lineage;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Chromosome<T> {
     genes: Vec<T>,
     pub fitness: f64,
+    // Only set in multi-objective mode (see `pareto::Pareto`), where `fitness` is derived from the Pareto
+    // front and the crowding distance of the chromosome.
+    pub pareto: Option<ParetoRank>,
+    // Only set when the genealogy is recorded (see `tracking::Genealogy`).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub lineage: Option<Lineage>,
+    evaluated: bool,
 }
 
 impl<T> Chromosome<T> {
     pub fn new(genes: Vec<T>, fitness: f64) -> Self {
-        Chromosome { genes: genes, fitness: fitness }
+        Chromosome {
+            genes: genes,
+            fitness: fitness,
+            pareto: None,
+            lineage: None,
+            evaluated: true,
+        }
     }
 
     pub fn genes(&self) -> &[T] {
         &self.genes
     }
+
+    // A chromosome is evaluated once its fitness was set through `new` or `set_fitness`. Borrowing the genes
+    // mutably marks the chromosome as unevaluated again, since its fitness may no longer be accurate.
+    pub fn is_evaluated(&self) -> bool {
+        self.evaluated
+    }
+
+    pub fn set_fitness(&mut self, fitness: f64) {
+        self.fitness = fitness;
+        self.evaluated = true;
+    }
+
+    fn invalidate(&mut self) {
+        self.evaluated = false;
+    }
 }
 
-forward_as!(Chromosome, T, genes);
-forward_index!(Chromosome, T, genes);
-forward_into_iter!(Chromosome, T, genes);
+forward_as!(Chromosome, T, genes, invalidate);
+forward_index!(Chromosome, T, genes, invalidate);
+forward_into_iter!(Chromosome, T, genes, invalidate);
 
 impl<T> Clone for Chromosome<T> where T: Clone {
     fn clone(&self) -> Self {
         Chromosome {
             genes: self.genes.clone(),
             fitness: self.fitness,
+            pareto: self.pareto.clone(),
+            lineage: self.lineage.clone(),
+            evaluated: self.evaluated,
         }
     }
 }
@@ -33,6 +68,9 @@ impl<T> From<Vec<T>> for Chromosome<T> {
         Chromosome {
             genes: genes,
             fitness: 0.0,
+            pareto: None,
+            lineage: None,
+            evaluated: false,
         }
     }
 }
@@ -40,7 +78,8 @@ impl<T> From<Vec<T>> for Chromosome<T> {
 impl<T> Eq for Chromosome<T> {}
 
 // It is assumed that a chromosome's fitness may never be NaN, so Ord is implemented to allow the use of
-// useful standard library functions (sort, max, min, etc.).
+// useful standard library functions (sort, max, min, etc.). Note that this compares the raw fitness values,
+// whether a higher value is actually better depends on the `Objective` of the generation.
 impl<T> Ord for Chromosome<T> {
     fn cmp(&self, other: &Self) -> Ordering {
         if let Some(ordering) = self.partial_cmp(other) {
@@ -63,3 +102,24 @@ impl<T> PartialOrd for Chromosome<T> {
     }
 }
 
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use chromosome::Chromosome;
+    use pareto::ParetoRank;
+
+    #[test]
+    fn chromosome_serde_round_trip() {
+        let mut chromosome = Chromosome::new(vec![1, 2, 3], 4.5);
+        chromosome.pareto = Some(ParetoRank { objectives: vec![1.0, -2.0], front: 2, crowding: 0.5 });
+        let json = ::serde_json::to_string(&chromosome).unwrap();
+        let restored: Chromosome<i32> = ::serde_json::from_str(&json).unwrap();
+
+        assert!(restored.genes() == &[1, 2, 3] && restored.fitness == 4.5);
+        assert!(restored.pareto == chromosome.pareto);
+        assert!(restored.is_evaluated());
+
+        let json = ::serde_json::to_string(&Chromosome::from(vec![1])).unwrap();
+        let restored: Chromosome<i32> = ::serde_json::from_str(&json).unwrap();
+        assert!(!restored.is_evaluated());
+    }
+}
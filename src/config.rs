@@ -0,0 +1,626 @@
+use std::error;
+use std::fmt;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, Read};
+use std::path::Path;
+use std::time::Duration;
+
+use serde_json;
+use toml;
+
+use algorithm::{Algorithm, DynAlgorithm};
+use crossover::*;
+use diversity::Measure;
+use evaluation::Evaluate;
+use mutation::*;
+use objective::Objective;
+use rand::SeedableRng;
+use random::Xorshift128;
+use reinsertion::*;
+use schedule::*;
+use selection::*;
+use termination::*;
+use tracking::*;
+
+// A run configuration, usually loaded from a JSON or TOML file. Every operator is given as a table with a
+// `type` key naming the operator and one key for each of its parameters, e.g.
+//
+// ```toml
+// [selection]
+// type = "tournament"
+// retain_ratio = 0.9
+// size = 5
+//
+// [crossover]
+// type = "order1"
+// rate = 0.8
+// ```
+//
+// Operators that need the fitness function or the problem (`FitnessBased`, `Nsga2`, `UniformMutation`) can
+// only be set up in code.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Config {
+    pub selection: SelectionConfig,
+    pub crossover: CrossoverConfig,
+    pub mutation: MutationConfig,
+    pub reinsertion: ReinsertionConfig,
+    pub termination: TerminationConfig,
+    #[serde(default)]
+    pub tracking: TrackingConfig,
+    #[serde(default)]
+    pub objective: Objective,
+    #[serde(default)]
+    pub seed: Option<[u32; 4]>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SelectionConfig {
+    Boltzmann {
+        retain_ratio: f64,
+        temperature: f64,
+        #[serde(default)]
+        cooling_rate: Option<f64>,
+        #[serde(default)]
+        min_temperature: Option<f64>,
+    },
+    CrowdedTournament { retain_ratio: f64, size: usize },
+    ExponentialRank { retain_ratio: f64, base: f64 },
+    LinearRank { retain_ratio: f64, pressure: f64 },
+    Roulette { retain_ratio: f64 },
+    StochasticUniversal { retain_ratio: f64 },
+    Tournament { retain_ratio: f64, size: usize },
+    Truncation { retain_ratio: f64, fraction: f64 },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CrossoverConfig {
+    pub rate: f64,
+    #[serde(flatten)]
+    pub operator: CrossoverOperator,
+    #[serde(default)]
+    pub schedule: Option<ScheduleConfig>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CrossoverOperator {
+    CutAndSplice,
+    Cycle,
+    EdgeRecombination,
+    HalfUniform,
+    OnePoint,
+    Order1,
+    PartiallyMapped,
+    PrecedencePreservative,
+    ThreeParent,
+    TwoPoint,
+    Uniform { probability: f64 },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MutationConfig {
+    pub rate: f64,
+    #[serde(flatten)]
+    pub operator: MutationOperator,
+    #[serde(default)]
+    pub schedule: Option<ScheduleConfig>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduleConfig {
+    DiversityDriven { measure: Measure, threshold: f64, low: f64, high: f64 },
+    ExponentialDecay { start: f64, end: f64, factor: f64 },
+    LinearDecay { start: f64, end: f64, generations: usize },
+    OneFifth { factor: f64, min: f64, max: f64 },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MutationOperator {
+    CentreInverse,
+    FlipBit,
+    PartialShuffle { n: usize },
+    ReverseSequence,
+    Shuffle,
+    Thrors,
+    Twors,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReinsertionConfig {
+    Elitist,
+    Pure,
+    Uniform,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TerminationConfig {
+    All { conditions: Vec<TerminationConfig> },
+    Any { conditions: Vec<TerminationConfig> },
+    Diversity { measure: Measure, threshold: f64 },
+    EvaluationBudget { evaluations: usize },
+    FitnessConvergence { generations: usize, epsilon: f64 },
+    FitnessThreshold { threshold: f64 },
+    Iterations { iterations: isize },
+    Not { condition: Box<TerminationConfig> },
+    TimeLimit { seconds: f64 },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TrackingConfig {
+    BestSolution,
+    Genealogy,
+    HallOfFame {
+        size: usize,
+        #[serde(default)]
+        min_distance: f64,
+    },
+    ParetoFront,
+    Statistics,
+}
+
+impl Default for TrackingConfig {
+    fn default() -> Self {
+        TrackingConfig::BestSolution
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Toml(toml::de::Error),
+    Invalid(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref err) => write!(f, "Could not read the configuration: {}", err),
+            Error::Json(ref err) => write!(f, "Invalid JSON configuration: {}", err),
+            Error::Toml(ref err) => write!(f, "Invalid TOML configuration: {}", err),
+            Error::Invalid(ref message) => write!(f, "Invalid configuration: {}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::Io(_) => "could not read the configuration",
+            Error::Json(_) => "invalid JSON configuration",
+            Error::Toml(_) => "invalid TOML configuration",
+            Error::Invalid(_) => "invalid configuration",
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(err: toml::de::Error) -> Self {
+        Error::Toml(err)
+    }
+}
+
+// Genes that can be used with a configuration. Every gene type supports the operators that only need
+// `Clone + PartialEq`, the others are opted into by the gene type.
+pub trait Gene: Clone + PartialEq + 'static {
+    fn crossover(_: &CrossoverOperator) -> Option<Box<DynCrossover<Self>>> {
+        None
+    }
+
+    fn mutation(_: &MutationOperator) -> Option<Box<DynMutation<Self>>> {
+        None
+    }
+}
+
+fn hashed_crossover<T>(operator: &CrossoverOperator) -> Option<Box<DynCrossover<T>>>
+    where T: Clone + Eq + Hash + 'static {
+
+    match *operator {
+        CrossoverOperator::Cycle => Some(Box::new(Cycle::new())),
+        CrossoverOperator::EdgeRecombination => Some(Box::new(EdgeRecombination::new())),
+        CrossoverOperator::PartiallyMapped => Some(Box::new(PartiallyMapped::new())),
+        _ => None,
+    }
+}
+
+macro_rules! hashed_gene {
+    ($($Type: ty),*) => {
+        $(
+            impl Gene for $Type {
+                fn crossover(operator: &CrossoverOperator) -> Option<Box<DynCrossover<Self>>> {
+                    hashed_crossover(operator)
+                }
+            }
+        )*
+    };
+}
+
+hashed_gene!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, char, String);
+
+impl Gene for bool {
+    fn crossover(operator: &CrossoverOperator) -> Option<Box<DynCrossover<Self>>> {
+        hashed_crossover(operator)
+    }
+
+    fn mutation(operator: &MutationOperator) -> Option<Box<DynMutation<Self>>> {
+        match *operator {
+            MutationOperator::FlipBit => Some(Box::new(FlipBit::new())),
+            _ => None,
+        }
+    }
+}
+
+impl Gene for f32 {}
+impl Gene for f64 {}
+
+impl Config {
+    pub fn from_json(contents: &str) -> Result<Self, Error> {
+        Ok(try!(serde_json::from_str(contents)))
+    }
+
+    pub fn from_toml(contents: &str) -> Result<Self, Error> {
+        Ok(try!(toml::from_str(contents)))
+    }
+
+    // Loads a configuration from a `.json` or a `.toml` file.
+    pub fn load<P>(path: P) -> Result<Self, Error> where P: AsRef<Path> {
+        let path = path.as_ref();
+        let mut contents = String::new();
+        try!(try!(File::open(path)).read_to_string(&mut contents));
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Config::from_json(&contents),
+            Some("toml") => Config::from_toml(&contents),
+            _ => Err(Error::Invalid(format!("Unknown configuration format: {}", path.display()))),
+        }
+    }
+
+    // Builds the algorithm, or returns `Error::Invalid` if an operator isn't supported for the genes or one
+    // of the parameters is out of range.
+    pub fn algorithm<'a, T, F>(&self, fitness: &'a F) -> Result<DynAlgorithm<'a, T, F>, Error>
+        where T: Gene, F: 'a + Evaluate<T> + ?Sized {
+
+        if let Some(objective) = fitness.objective() {
+            try!(check(self.objective == objective,
+                       &format!("the fitness function can only be used to {}", objective)));
+        }
+        try!(check(self.seed != Some([0, 0, 0, 0]), "the seed can't be all zeros"));
+
+        let mut algorithm = Algorithm::new(fitness,
+                                           try!(self.selection()),
+                                           (try!(self.crossover()), self.crossover.rate),
+                                           (try!(self.mutation()), self.mutation.rate),
+                                           self.reinsertion(),
+                                           try!(self.tracking()))
+            .objective(self.objective);
+        if let Some(ref config) = self.crossover.schedule {
+            algorithm = algorithm.crossover_schedule(try!(schedule::<T>(config)));
+        }
+        if let Some(ref config) = self.mutation.schedule {
+            algorithm = algorithm.mutation_schedule(try!(schedule::<T>(config)));
+        }
+
+        Ok(match self.seed {
+            Some(seed) => algorithm.rng(Xorshift128::from_seed(seed)),
+            None => algorithm,
+        })
+    }
+
+    // Builds the termination condition, or returns `Error::Invalid` if one of its parameters is out of range
+    // or a combination of conditions is empty.
+    pub fn termination<T>(&self) -> Result<Box<Termination<T>>, Error> where T: PartialEq {
+        termination(&self.termination)
+    }
+
+    fn selection<T>(&self) -> Result<Box<DynSelection<T>>, Error> where T: Gene {
+        let selection: Box<DynSelection<T>> = match self.selection {
+            SelectionConfig::Boltzmann { retain_ratio, temperature, cooling_rate, min_temperature } => {
+                try!(check(temperature > 0.0, "the Boltzmann temperature has to be positive"));
+                match (cooling_rate, min_temperature) {
+                    (None, None) => Box::new(Boltzmann::new(retain_ratio, temperature)),
+                    (Some(cooling), Some(minimum)) => {
+                        try!(check(minimum > 0.0, "the minimum Boltzmann temperature has to be positive"));
+                        Box::new(Boltzmann::annealed(retain_ratio, temperature, cooling, minimum))
+                    },
+                    _ => {
+                        let message = "an annealed Boltzmann selection needs both `cooling_rate` and \
+                                       `min_temperature`";
+                        return Err(Error::Invalid(message.to_owned()));
+                    },
+                }
+            },
+            SelectionConfig::CrowdedTournament { retain_ratio, size } => {
+                Box::new(CrowdedTournament::new(retain_ratio, size))
+            },
+            SelectionConfig::ExponentialRank { retain_ratio, base } => {
+                try!(check(base > 0.0 && base < 1.0,
+                           "the ranking base has to be between 0 and 1 (exclusive)"));
+                Box::new(Rank::exponential(retain_ratio, base))
+            },
+            SelectionConfig::LinearRank { retain_ratio, pressure } => {
+                try!(check(pressure >= 1.0 && pressure <= 2.0,
+                           "the ranking pressure has to be between 1 and 2"));
+                Box::new(Rank::linear(retain_ratio, pressure))
+            },
+            SelectionConfig::Roulette { retain_ratio } => Box::new(Roulette::new(retain_ratio)),
+            SelectionConfig::StochasticUniversal { retain_ratio } => {
+                Box::new(StochasticUniversal::new(retain_ratio))
+            },
+            SelectionConfig::Tournament { retain_ratio, size } => {
+                Box::new(Tournament::new(retain_ratio, size))
+            },
+            SelectionConfig::Truncation { retain_ratio, fraction } => {
+                try!(check(fraction > 0.0 && fraction <= 1.0,
+                           "the truncation fraction has to be between 0 (exclusive) and 1"));
+                Box::new(Truncation::new(retain_ratio, fraction))
+            },
+        };
+        Ok(selection)
+    }
+
+    fn crossover<T>(&self) -> Result<Box<DynCrossover<T>>, Error> where T: Gene {
+        let crossover: Box<DynCrossover<T>> = match self.crossover.operator {
+            CrossoverOperator::CutAndSplice => Box::new(CutAndSplice::new()),
+            CrossoverOperator::HalfUniform => Box::new(HalfUniform::new()),
+            CrossoverOperator::OnePoint => Box::new(OnePoint::new()),
+            CrossoverOperator::Order1 => Box::new(Order1::new()),
+            CrossoverOperator::PrecedencePreservative => Box::new(PrecedencePreservative::new()),
+            CrossoverOperator::ThreeParent => Box::new(ThreeParent::new()),
+            CrossoverOperator::TwoPoint => Box::new(TwoPoint::new()),
+            CrossoverOperator::Uniform { probability } => Box::new(UniformCrossover::new(probability)),
+            ref operator => match T::crossover(operator) {
+                Some(crossover) => crossover,
+                None => return Err(unsupported(operator)),
+            },
+        };
+        Ok(crossover)
+    }
+
+    fn mutation<T>(&self) -> Result<Box<DynMutation<T>>, Error> where T: Gene {
+        let mutation: Box<DynMutation<T>> = match self.mutation.operator {
+            MutationOperator::CentreInverse => Box::new(CentreInverse::new()),
+            MutationOperator::PartialShuffle { n } => Box::new(PartialShuffle::new(n)),
+            MutationOperator::ReverseSequence => Box::new(ReverseSequence::new()),
+            MutationOperator::Shuffle => Box::new(Shuffle::new()),
+            MutationOperator::Thrors => Box::new(Thrors::new()),
+            MutationOperator::Twors => Box::new(Twors::new()),
+            ref operator => match T::mutation(operator) {
+                Some(mutation) => mutation,
+                None => return Err(unsupported(operator)),
+            },
+        };
+        Ok(mutation)
+    }
+
+    fn reinsertion<T>(&self) -> Box<DynReinsertion<T>> where T: Gene {
+        let reinsertion: Box<DynReinsertion<T>> = match self.reinsertion {
+            ReinsertionConfig::Elitist => Box::new(Elitist::new()),
+            ReinsertionConfig::Pure => Box::new(Pure::new()),
+            ReinsertionConfig::Uniform => Box::new(UniformReinsertion::new()),
+        };
+        reinsertion
+    }
+
+    fn tracking<T>(&self) -> Result<Box<DynTracking<T>>, Error> where T: Gene {
+        let tracking: Box<DynTracking<T>> = match self.tracking {
+            TrackingConfig::BestSolution => Box::new(BestSolution::new()),
+            TrackingConfig::Genealogy => Box::new(Genealogy::new()),
+            TrackingConfig::HallOfFame { size, min_distance } => {
+                try!(check(size > 0, "a hall of fame has to keep at least one chromosome"));
+                Box::new(HallOfFame::new(size).min_distance(min_distance))
+            },
+            TrackingConfig::ParetoFront => Box::new(ParetoFront::new()),
+            TrackingConfig::Statistics => Box::new(Statistics::new()),
+        };
+        Ok(tracking)
+    }
+}
+
+fn schedule<T>(config: &ScheduleConfig) -> Result<Box<Schedule<T> + Send>, Error> where T: PartialEq {
+    let schedule: Box<Schedule<T> + Send> = match *config {
+        ScheduleConfig::DiversityDriven { measure, threshold, low, high } => {
+            try!(check(threshold > 0.0, "the diversity threshold has to be positive"));
+            Box::new(DiversityDriven::new(measure, threshold, low, high))
+        },
+        ScheduleConfig::ExponentialDecay { start, end, factor } => {
+            try!(check(factor >= 0.0 && factor <= 1.0, "the decay factor has to be between 0 and 1"));
+            Box::new(ExponentialDecay::new(start, end, factor))
+        },
+        ScheduleConfig::LinearDecay { start, end, generations } => {
+            try!(check(generations > 0, "a linear decay needs at least one generation"));
+            Box::new(LinearDecay::new(start, end, generations))
+        },
+        ScheduleConfig::OneFifth { factor, min, max } => {
+            try!(check(factor > 0.0 && factor <= 1.0, "the adjustment factor has to be between 0 and 1"));
+            Box::new(OneFifth::new(factor, min, max))
+        },
+    };
+    Ok(schedule)
+}
+
+fn termination<T>(config: &TerminationConfig) -> Result<Box<Termination<T>>, Error> where T: PartialEq {
+    let termination: Box<Termination<T>> = match *config {
+        TerminationConfig::All { ref conditions } => {
+            try!(combine(conditions, |first, second| Box::new(first.and(second))))
+        },
+        TerminationConfig::Any { ref conditions } => {
+            try!(combine(conditions, |first, second| Box::new(first.or(second))))
+        },
+        TerminationConfig::Diversity { measure, threshold } => Box::new(Diversity::new(measure, threshold)),
+        TerminationConfig::EvaluationBudget { evaluations } => Box::new(EvaluationBudget::new(evaluations)),
+        TerminationConfig::FitnessConvergence { generations, epsilon } => {
+            Box::new(FitnessConvergence::new(generations, epsilon))
+        },
+        TerminationConfig::FitnessThreshold { threshold } => Box::new(FitnessThreshold::new(threshold)),
+        TerminationConfig::Iterations { iterations } => {
+            try!(check(iterations >= 0, "the number of iterations can't be negative"));
+            Box::new(Iterations::new(iterations))
+        },
+        TerminationConfig::Not { ref condition } => Box::new(try!(termination::<T>(condition)).not()),
+        TerminationConfig::TimeLimit { seconds } => {
+            let valid = seconds >= 0.0 && seconds.is_finite();
+            try!(check(valid, "the time limit has to be a finite, non-negative number of seconds"));
+            let nanos = (seconds.fract() * 1e9) as u32;
+            Box::new(TimeLimit::new(Duration::new(seconds as u64, nanos)))
+        },
+    };
+    Ok(termination)
+}
+
+fn combine<T, F>(conditions: &[TerminationConfig], combinator: F) -> Result<Box<Termination<T>>, Error>
+    where T: PartialEq, F: Fn(Box<Termination<T>>, Box<Termination<T>>) -> Box<Termination<T>> {
+
+    try!(check(!conditions.is_empty(), "combined termination conditions can't be empty"));
+    let mut combined = try!(termination(&conditions[0]));
+    for condition in &conditions[1..] {
+        combined = combinator(combined, try!(termination(condition)));
+    }
+    Ok(combined)
+}
+
+fn unsupported<O>(operator: &O) -> Error where O: fmt::Debug {
+    Error::Invalid(format!("{:?} is not supported for these genes", operator))
+}
+
+// The operators panic on parameters that are out of range, so the values from the configuration are checked
+// before they're passed on.
+fn check(valid: bool, message: &str) -> Result<(), Error> {
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Invalid(message.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use config::Config;
+    use objective::Objective;
+    use pareto::Pareto;
+    use problem::{Problem, Permutation};
+    use random::Xorshift128;
+    use tracking::BestSolution;
+
+    const CONFIG: &'static str = r#"
+        seed = [1, 2, 3, 4]
+
+        [selection]
+        type = "tournament"
+        retain_ratio = 0.9
+        size = 3
+
+        [crossover]
+        type = "partially_mapped"
+        rate = 0.8
+
+        [mutation]
+        type = "twors"
+        rate = 0.2
+
+        [reinsertion]
+        type = "elitist"
+
+        [termination]
+        type = "iterations"
+        iterations = 20
+    "#;
+
+    #[test]
+    fn config_from_toml() {
+        let fitness = |genes: &[usize]| genes[0] as f64;
+        let config = Config::from_toml(CONFIG).unwrap();
+        let mut algorithm = config.algorithm(&fitness).unwrap();
+        let mut rng = Xorshift128::from_seed([1, 2, 3, 4]);
+        let population = Permutation::from(0..10usize).generate_population(20, &mut rng);
+
+        let tracking = algorithm.evolve(population, config.termination().unwrap());
+        let best = (**tracking).as_any().downcast_ref::<BestSolution<usize>>().unwrap().best();
+        assert!(best.0.len() == 10);
+    }
+
+    #[test]
+    fn config_unsupported_operator() {
+        let fitness = |genes: &[f64]| genes[0];
+        let config = Config::from_toml(CONFIG).unwrap();
+        assert!(config.algorithm::<f64, _>(&fitness).is_err());
+    }
+
+    #[test]
+    fn config_rejects_invalid_values() {
+        let fitness = |genes: &[usize]| genes[0] as f64;
+        let invalid = |contents: String| {
+            let config = Config::from_toml(&contents).unwrap();
+            config.algorithm::<usize, _>(&fitness).is_err()
+        };
+        let selection = |selection: &str| CONFIG.replace("type = \"tournament\"", selection);
+
+        assert!(invalid(CONFIG.replace("[1, 2, 3, 4]", "[0, 0, 0, 0]")));
+        assert!(invalid(selection("type = \"boltzmann\"\ntemperature = 0.0")));
+        assert!(invalid(selection("type = \"exponential_rank\"\nbase = 1.0")));
+        assert!(invalid(selection("type = \"linear_rank\"\npressure = 2.5")));
+        assert!(invalid(selection("type = \"truncation\"\nfraction = 0.0")));
+        let tracking = "[tracking]\ntype = \"hall_of_fame\"\nsize = 0\n\n[termination]";
+        assert!(invalid(CONFIG.replace("[termination]", tracking)));
+    }
+
+    #[test]
+    fn config_rejects_invalid_schedules() {
+        let fitness = |genes: &[usize]| genes[0] as f64;
+        let invalid = |schedule: &str| {
+            let schedule = format!("rate = 0.2\nschedule = {{ {} }}", schedule);
+            let config = Config::from_toml(&CONFIG.replace("rate = 0.2", &schedule)).unwrap();
+            config.algorithm::<usize, _>(&fitness).is_err()
+        };
+
+        assert!(!invalid("type = \"linear_decay\", start = 0.2, end = 0.0, generations = 10"));
+        assert!(invalid("type = \"linear_decay\", start = 0.2, end = 0.0, generations = 0"));
+        assert!(invalid("type = \"exponential_decay\", start = 0.2, end = 0.0, factor = 1.5"));
+        assert!(invalid("type = \"one_fifth\", factor = 0.0, min = 0.0, max = 1.0"));
+        assert!(invalid("type = \"diversity_driven\", measure = \"hamming\", threshold = 0.0, low = 0.1, \
+                         high = 0.5"));
+    }
+
+    #[test]
+    fn config_rejects_empty_combinations() {
+        let contents = CONFIG.replace("type = \"iterations\"", "type = \"any\"\nconditions = []");
+        let config = Config::from_toml(&contents).unwrap();
+        assert!(config.termination::<usize>().is_err());
+
+        let contents = CONFIG.replace("iterations = 20", "iterations = -5");
+        let config = Config::from_toml(&contents).unwrap();
+        assert!(config.termination::<usize>().is_err());
+    }
+
+    #[test]
+    fn config_rejects_objectives_the_fitness_function_cannot_use() {
+        let objectives = |genes: &[usize]| vec![genes[0] as f64, genes[1] as f64];
+        let pareto = Pareto::new(&objectives, vec![Objective::Minimize, Objective::Maximize]);
+
+        let config = Config::from_toml(CONFIG).unwrap();
+        assert!(config.algorithm::<usize, _>(&pareto).is_ok());
+        let contents = CONFIG.replace("seed", "objective = \"minimize\"\nseed");
+        let config = Config::from_toml(&contents).unwrap();
+        assert!(config.algorithm::<usize, _>(&pareto).is_err());
+    }
+}
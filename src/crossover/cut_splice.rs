@@ -1,8 +1,9 @@
-use rand;
+use rand::Rng;
 use crossover::Crossover;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CutAndSplice {
     preset_split: Option<(usize, usize)>,
 }
@@ -26,8 +27,7 @@ impl<T> Crossover<T> for CutAndSplice where T: Clone {
         2
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
-        let mut rng = rand::thread_rng();
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2) = (parents[0].as_ref(), parents[1].as_ref());
         let (length1, length2) = (parent1.len(), parent2.len());
         let (split1, split2) = self.preset_split
@@ -36,6 +36,10 @@ impl<T> Crossover<T> for CutAndSplice where T: Clone {
         vec![join!(parent1[0..split1], parent2[split2..length2]),
              join!(parent2[0..split2], parent1[split1..length1])]
     }
+
+    fn name(&self) -> &'static str {
+        "CutAndSplice"
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,12 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 
+use rand::Rng;
+
 use crossover::Crossover;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Cycle;
 
 impl Cycle {
@@ -21,7 +24,7 @@ impl<T> Crossover<T> for Cycle where T: Clone + Eq + Hash {
         2
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
+    fn cross<U, R>(&self, parents: &[U], _: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2) = (parents[0].as_ref(), parents[1].as_ref());
         let cycles = gather_cycles(parent1, parent2);
         let (mut child1, mut child2) = (Vec::from(parent1), Vec::from(parent2));
@@ -40,6 +43,10 @@ impl<T> Crossover<T> for Cycle where T: Clone + Eq + Hash {
         }
         vec![child1, child2]
     }
+
+    fn name(&self) -> &'static str {
+        "Cycle"
+    }
 }
 
 fn gather_cycles<T>(parent1: &[T], parent2: &[T]) -> Vec<Vec<usize>>
@@ -2,12 +2,13 @@ use std::hash::Hash;
 
 use itertools::Itertools;
 use linear_map::LinearMap;
-use rand::{self, Rng};
+use rand::Rng;
 
 use crossover::Crossover;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EdgeRecombination;
 
 impl EdgeRecombination {
@@ -25,8 +26,7 @@ impl<T> Crossover<T> for EdgeRecombination where T: Clone + Eq + Hash {
         2
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
-        let mut rng = rand::thread_rng();
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2) = (parents[0].as_ref(), parents[1].as_ref());
         let length = parent1.len();
         let adjacency = adjacency_matrix(parent1, parent2);
@@ -34,8 +34,12 @@ impl<T> Crossover<T> for EdgeRecombination where T: Clone + Eq + Hash {
         let start0 = rng.choose1(parent1);
         let start1 = rng.choose1(parent2);
 
-        vec![edge_recombination(start0, length, &adjacency, &mut rng),
-             edge_recombination(start1, length, &adjacency, &mut rng)]
+        vec![edge_recombination(start0, length, &adjacency, rng),
+             edge_recombination(start1, length, &adjacency, rng)]
+    }
+
+    fn name(&self) -> &'static str {
+        "EdgeRecombination"
     }
 }
 
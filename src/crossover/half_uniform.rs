@@ -1,7 +1,8 @@
-use rand::{self, Rng};
+use rand::Rng;
 use crossover::Crossover;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HalfUniform;
 
 impl HalfUniform {
@@ -19,8 +20,7 @@ impl<T> Crossover<T> for HalfUniform where T: Clone + PartialEq {
         2
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
-        let mut rng = rand::thread_rng();
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2) = (parents[0].as_ref(), parents[1].as_ref());
         let (mut child1, mut child2) = (Vec::from(parent1), Vec::from(parent2));
 
@@ -42,6 +42,10 @@ impl<T> Crossover<T> for HalfUniform where T: Clone + PartialEq {
         }
         vec![child1, child2]
     }
+
+    fn name(&self) -> &'static str {
+        "HalfUniform"
+    }
 }
 
 #[cfg(test)]
@@ -67,7 +71,7 @@ mod tests {
                                           vec![1; length]];
 
         let half_uniform = HalfUniform::new();
-        let children = half_uniform.cross(&parents);
+        let children = half_uniform.cross(&parents, &mut ::rand::thread_rng());
 
         let check_genes = |child: &Vec<i32>| {
             let zeros = child.iter().fold(0, |acc, x| if *x == 0 { acc + 1 } else { acc });
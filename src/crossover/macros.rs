@@ -17,7 +17,7 @@ macro_rules! test_crossover {
 
             let parents: Vec<Vec<$Type>> = vec![$(vec![$($parent),+]),+];
             let expected: Vec<Vec<$Type>> = vec![$(vec![$($child),*]),*];
-            let actual = $crossover.cross(&parents);
+            let actual = $crossover.cross(&parents, &mut ::rand::thread_rng());
             assert!(expected == actual);
         }
     };
@@ -36,7 +36,7 @@ macro_rules! test_crossover_panic {
 
             let parents: Vec<Vec<$Type>> = vec![$(vec![$($parent),+]),+];
             let expected: Vec<Vec<$Type>> = vec![$(vec![$($child),*]),*];
-            let actual = $crossover.cross(&parents);
+            let actual = $crossover.cross(&parents, &mut ::rand::thread_rng());
             if !expected.is_empty() {
                 assert!(expected.len() == actual.len());
             }
@@ -56,10 +56,11 @@ macro_rules! bench_crossover {
 
             //let parents: Vec<Vec<$Type>> = vec![$(vec![$($parent),+]),+];
             let permutation = Permutation::from(0..10000);
-            let parents = permutation.generate_population(2);
+            let parents = permutation.generate_population(2, &mut ::rand::thread_rng());
             let cross = $crossover;
+            let mut rng = ::rand::thread_rng();
             b.iter(|| {
-                ::test::black_box(cross.cross(&parents));
+                ::test::black_box(cross.cross(&parents, &mut rng));
             });
         }
     };
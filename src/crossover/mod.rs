@@ -13,6 +13,10 @@ mod three_parent;
 mod two_point;
 mod uniform;
 
+use rand::Rng;
+
+use utility::short_type_name;
+
 pub use self::cut_splice::CutAndSplice;
 pub use self::cycle::Cycle;
 pub use self::edge_recombination::EdgeRecombination;
@@ -28,5 +32,77 @@ pub use self::uniform::UniformCrossover;
 pub trait Crossover<T> {
     fn parents(&self) -> usize;
     fn children(&self) -> usize;
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]>;
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng;
+
+    // The name of the operator, which is recorded in the genealogy of the chromosomes it produces. The
+    // default is derived from the type name, which isn't guaranteed to be stable, so operators should
+    // override it.
+    fn name(&self) -> &'static str {
+        short_type_name::<Self>()
+    }
+}
+
+// An object safe version of `Crossover`, implemented for every crossover operator. Boxed (and borrowed)
+// operators implement `Crossover` again, so they can be used wherever a concrete operator is expected.
+pub trait DynCrossover<T> {
+    fn dyn_parents(&self) -> usize;
+    fn dyn_children(&self) -> usize;
+    fn dyn_cross(&self, parents: &[&[T]], rng: &mut Rng) -> Vec<Vec<T>>;
+    fn dyn_name(&self) -> &'static str;
+}
+
+impl<T, C> DynCrossover<T> for C where C: Crossover<T> {
+    fn dyn_parents(&self) -> usize {
+        self.parents()
+    }
+
+    fn dyn_children(&self) -> usize {
+        self.children()
+    }
+
+    fn dyn_cross(&self, parents: &[&[T]], mut rng: &mut Rng) -> Vec<Vec<T>> {
+        self.cross(parents, &mut rng)
+    }
+
+    fn dyn_name(&self) -> &'static str {
+        self.name()
+    }
+}
+
+impl<'a, T> Crossover<T> for Box<DynCrossover<T> + 'a> {
+    fn parents(&self) -> usize {
+        (**self).dyn_parents()
+    }
+
+    fn children(&self) -> usize {
+        (**self).dyn_children()
+    }
+
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
+        let parents = parents.iter().map(|p| p.as_ref()).collect::<Vec<_>>();
+        (**self).dyn_cross(&parents, rng)
+    }
+
+    fn name(&self) -> &'static str {
+        (**self).dyn_name()
+    }
+}
+
+impl<'a, 'b, T> Crossover<T> for &'b (DynCrossover<T> + 'a) {
+    fn parents(&self) -> usize {
+        (**self).dyn_parents()
+    }
+
+    fn children(&self) -> usize {
+        (**self).dyn_children()
+    }
+
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
+        let parents = parents.iter().map(|p| p.as_ref()).collect::<Vec<_>>();
+        (**self).dyn_cross(&parents, rng)
+    }
+
+    fn name(&self) -> &'static str {
+        (**self).dyn_name()
+    }
 }
@@ -1,8 +1,9 @@
-use rand;
+use rand::Rng;
 use crossover::Crossover;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OnePoint {
     preset_split: Option<usize>,
 }
@@ -26,14 +27,17 @@ impl<T> Crossover<T> for OnePoint where T: Clone {
         2
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
-        let mut rng = rand::thread_rng();
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2) = (parents[0].as_ref(), parents[1].as_ref());
         let length = parent1.len();
         let split = self.preset_split.unwrap_or(rng.index(parent1));
         vec![join!(parent1[0..split], parent2[split..length]),
              join!(parent2[0..split], parent1[split..length])]
     }
+
+    fn name(&self) -> &'static str {
+        "OnePoint"
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,10 @@
 use std::ops::Range;
-use rand;
+use rand::Rng;
 use crossover::Crossover;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Order1 {
     preset_split: Option<(usize, usize)>,
 }
@@ -30,14 +31,17 @@ impl<T> Crossover<T> for Order1 where T: Clone + PartialEq {
         2
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
-        let mut rng = rand::thread_rng();
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2) = (parents[0].as_ref(), parents[1].as_ref());
         let split = self.preset_split.unwrap_or(rng.range_indexes(parent1));
 
         vec![order1(parent1, parent2, split),
              order1(parent2, parent1, split)]
     }
+
+    fn name(&self) -> &'static str {
+        "Order1"
+    }
 }
 
 
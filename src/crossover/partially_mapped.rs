@@ -2,12 +2,13 @@ use std::collections::HashMap;
 use std::hash::Hash;
 use std::ops::Range;
 
-use rand;
+use rand::Rng;
 
 use crossover::Crossover;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PartiallyMapped {
     preset_split: Option<(usize, usize)>,
 }
@@ -34,14 +35,17 @@ impl<T> Crossover<T> for PartiallyMapped where T: Clone + Eq + Hash {
         2
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
-        let mut rng = rand::thread_rng();
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2) = (parents[0].as_ref(), parents[1].as_ref());
         let split = self.preset_split.unwrap_or(rng.range_indexes(parent1));
 
         vec![partially_mapped(parent1, parent2, split),
              partially_mapped(parent2, parent1, split)]
     }
+
+    fn name(&self) -> &'static str {
+        "PartiallyMapped"
+    }
 }
 
 fn partially_mapped<T>(parent1: &[T], parent2: &[T],
@@ -1,7 +1,8 @@
-use rand::{self, Rng};
+use rand::Rng;
 use crossover::Crossover;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PrecedencePreservative;
 
 impl PrecedencePreservative {
@@ -19,8 +20,7 @@ impl<T> Crossover<T> for PrecedencePreservative where T: Clone + PartialEq {
         1
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
-        let mut rng = rand::thread_rng();
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2) = (parents[0].as_ref(), parents[1].as_ref());
         let length = parent2.len();
         let (mut genes1, mut genes2) = (parent1.into(), parent2.into());
@@ -35,6 +35,10 @@ impl<T> Crossover<T> for PrecedencePreservative where T: Clone + PartialEq {
         }
         vec![child]
     }
+
+    fn name(&self) -> &'static str {
+        "PrecedencePreservative"
+    }
 }
 
 
@@ -1,6 +1,9 @@
+use rand::Rng;
+
 use crossover::Crossover;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ThreeParent;
 
 impl ThreeParent {
@@ -18,7 +21,7 @@ impl<T> Crossover<T> for ThreeParent where T: Clone + PartialEq {
         1
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
+    fn cross<U, R>(&self, parents: &[U], _: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2, parent3) = (parents[0].as_ref(),
                                            parents[1].as_ref(),
                                            parents[2].as_ref());
@@ -34,6 +37,10 @@ impl<T> Crossover<T> for ThreeParent where T: Clone + PartialEq {
             });
         vec![genes.collect()]
     }
+
+    fn name(&self) -> &'static str {
+        "ThreeParent"
+    }
 }
 
 #[cfg(test)]
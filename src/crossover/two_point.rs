@@ -1,11 +1,12 @@
 use std::ops::Range;
 
-use rand;
+use rand::Rng;
 
 use crossover::Crossover;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TwoPoint {
     preset_split: Option<(usize, usize)>,
 }
@@ -32,8 +33,7 @@ impl<T> Crossover<T> for TwoPoint where T: Clone {
         2
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
-        let mut rng = rand::thread_rng();
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2) = (parents[0].as_ref(), parents[1].as_ref());
         let length = parent1.len();
         let (mid_start, mid_end) = self.preset_split.unwrap_or(rng.range_indexes(parent1));
@@ -41,6 +41,10 @@ impl<T> Crossover<T> for TwoPoint where T: Clone {
         vec![join!(parent1[0..mid_start], parent2[mid_start..mid_end], parent1[mid_end..length]),
              join!(parent2[0..mid_start], parent1[mid_start..mid_end], parent2[mid_end..length])]
     }
+
+    fn name(&self) -> &'static str {
+        "TwoPoint"
+    }
 }
 
 #[cfg(test)]
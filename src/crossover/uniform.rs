@@ -1,9 +1,10 @@
-use rand;
+use rand::Rng;
 
 use crossover::Crossover;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UniformCrossover {
     pub probability: f64
 }
@@ -23,8 +24,7 @@ impl<T> Crossover<T> for UniformCrossover where T: Clone {
         2
     }
 
-    fn cross<U>(&self, parents: &[U]) -> Vec<Vec<T>> where U: AsRef<[T]> {
-        let mut rng = rand::thread_rng();
+    fn cross<U, R>(&self, parents: &[U], rng: &mut R) -> Vec<Vec<T>> where U: AsRef<[T]>, R: Rng {
         let (parent1, parent2) = (parents[0].as_ref(), parents[1].as_ref());
         let length = parent1.len();
         let (mut child1, mut child2) = (Vec::with_capacity(length), Vec::with_capacity(length));
@@ -41,6 +41,10 @@ impl<T> Crossover<T> for UniformCrossover where T: Clone {
 
         vec![child1, child2]
     }
+
+    fn name(&self) -> &'static str {
+        "UniformCrossover"
+    }
 }
 
 #[cfg(test)]
@@ -77,7 +81,7 @@ mod tests {
         let parents: Vec<Vec<i32>> = vec![vec![0; length],
                                                  vec![1; length]];
         let uniform = UniformCrossover::new(0.5);
-        let children = uniform.cross(&parents);
+        let children = uniform.cross(&parents, &mut ::rand::thread_rng());
 
         let check_genes = |child: &Vec<i32>| {
             let zeros = child.iter().fold(0, |acc, x| if *x == 0 { acc + 1 } else { acc });
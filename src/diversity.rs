@@ -0,0 +1,110 @@
+use generation::Generation;
+
+// Ways of measuring how diverse the chromosomes of a generation are, where a lower value always means a less
+// diverse population.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Measure {
+    // The mean pairwise Hamming distance, see `hamming_distance`.
+    Hamming,
+    // The ratio of distinct genotypes, see `unique_ratio`.
+    UniqueRatio,
+    // The standard deviation of the fitness, see `fitness_deviation`.
+    FitnessDeviation,
+}
+
+impl Measure {
+    pub fn of<T>(&self, generation: &Generation<T>) -> f64 where T: PartialEq {
+        match *self {
+            Measure::Hamming => hamming_distance(generation),
+            Measure::UniqueRatio => unique_ratio(generation),
+            Measure::FitnessDeviation => fitness_deviation(generation),
+        }
+    }
+}
+
+// The Hamming distance of every pair of chromosomes, as the fraction of positions in which their genes
+// differ, averaged over all pairs. The result lies between 0 (all chromosomes are equal) and 1.
+pub fn hamming_distance<T>(generation: &Generation<T>) -> f64 where T: PartialEq {
+    let size = generation.size();
+    if size < 2 {
+        return 0.0;
+    }
+
+    let mut total = 0.0;
+    for i in 0..size {
+        for j in (i + 1)..size {
+            total += distance(generation[i].genes(), generation[j].genes());
+        }
+    }
+
+    total / (size * (size - 1) / 2) as f64
+}
+
+// The fraction of positions in which the genes of two chromosomes differ. Genes beyond the end of the
+// shorter chromosome count as different.
+pub fn distance<T>(first: &[T], second: &[T]) -> f64 where T: PartialEq {
+    let length = first.len().max(second.len());
+    if length == 0 {
+        return 0.0;
+    }
+
+    let equal = first.iter().zip(second).filter(|&(a, b)| a == b).count();
+    (length - equal) as f64 / length as f64
+}
+
+// The number of distinct genotypes relative to the size of the generation. A single genotype still counts
+// as one, so only an empty generation measures 0.
+pub fn unique_ratio<T>(generation: &Generation<T>) -> f64 where T: PartialEq {
+    let size = generation.size();
+    if size == 0 {
+        return 0.0;
+    }
+
+    let unique = (0..size)
+        .filter(|&i| (0..i).all(|j| generation[i].genes() != generation[j].genes()))
+        .count();
+    unique as f64 / size as f64
+}
+
+// The (population) standard deviation of the fitness values in the generation.
+pub fn fitness_deviation<T>(generation: &Generation<T>) -> f64 {
+    let size = generation.size();
+    if size == 0 {
+        return 0.0;
+    }
+
+    let mean = generation.total_fitness() / size as f64;
+    let variance = generation.iter().fold(0.0, |sum, c| sum + (c.fitness - mean).powi(2)) / size as f64;
+    variance.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use diversity::{Measure, fitness_deviation, hamming_distance, unique_ratio};
+    use generation::Generation;
+    use objective::Objective;
+
+    #[test]
+    fn diversity_measures() {
+        let fitness = |genes: &[i32]| genes.iter().sum::<i32>() as f64;
+        let population = vec![vec![0, 0, 0, 0], vec![0, 0, 1, 1], vec![0, 0, 1, 1]];
+        let generation = Generation::new(population, &fitness, Objective::Maximize);
+
+        assert_approx_eq!(hamming_distance(&generation), 1.0 / 3.0, 1e-9);
+        assert_approx_eq!(unique_ratio(&generation), 2.0 / 3.0, 1e-9);
+        assert_approx_eq!(fitness_deviation(&generation), 8f64.sqrt() / 3.0, 1e-9);
+    }
+
+    #[test]
+    fn collapsed_population() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let population = vec![vec![3, 1], vec![3, 1], vec![3, 1]];
+        let generation = Generation::new(population, &fitness, Objective::Maximize);
+
+        assert!(Measure::Hamming.of(&generation) == 0.0);
+        assert!(Measure::UniqueRatio.of(&generation) == 1.0 / 3.0);
+        assert!(Measure::FitnessDeviation.of(&generation) == 0.0);
+    }
+}
@@ -0,0 +1,210 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use chromosome::Chromosome;
+use objective::Objective;
+
+// Evaluation is implemented for every fitness function. Only chromosomes that are not evaluated yet (new
+// offspring or chromosomes whose genes were borrowed mutably) are passed to the fitness function, and the
+// number of calls made is returned.
+pub trait Evaluate<T> {
+    fn evaluate(&self, chromosomes: &mut [Chromosome<T>]) -> usize;
+
+    // Fitness functions that rank the chromosomes relative to the rest of their generation (see
+    // `pareto::Pareto`) return the objective the ranks have to be used with. Such fitness values can't be
+    // cached, since they change whenever the generation does.
+    fn objective(&self) -> Option<Objective> {
+        None
+    }
+}
+
+impl<T, F> Evaluate<T> for F where F: Fn(&[T]) -> f64 {
+    fn evaluate(&self, chromosomes: &mut [Chromosome<T>]) -> usize {
+        let mut evaluations = 0;
+        for chromosome in chromosomes.iter_mut().filter(|c| !c.is_evaluated()) {
+            let fitness = self(chromosome.genes());
+            chromosome.set_fitness(fitness);
+            evaluations += 1;
+        }
+        evaluations
+    }
+}
+
+// Evaluates a fitness function on every logical CPU, which pays off when it's expensive. The pending
+// chromosomes are split into one chunk per CPU and each chunk is evaluated on its own thread, which requires
+// the fitness function to be `Sync` and the genes to be `Send`. Every chromosome is still evaluated exactly
+// once, so the results are identical to the serial evaluation. Only available with the `parallel` feature.
+#[cfg(feature = "parallel")]
+pub struct Parallel<'a, F>(pub &'a F) where F: 'a;
+
+#[cfg(feature = "parallel")]
+impl<'a, T, F> Evaluate<T> for Parallel<'a, F> where T: Send, F: 'a + Fn(&[T]) -> f64 + Sync {
+    fn evaluate(&self, chromosomes: &mut [Chromosome<T>]) -> usize {
+        use crossbeam;
+        use num_cpus;
+
+        let mut pending = chromosomes.iter_mut()
+            .filter(|c| !c.is_evaluated())
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            return 0;
+        }
+
+        let threads = num_cpus::get();
+        let chunk_size = (pending.len() + threads - 1) / threads;
+        let function = self.0;
+
+        crossbeam::scope(|scope| {
+            for chunk in pending.chunks_mut(chunk_size) {
+                scope.spawn(move |_| {
+                    for chromosome in chunk.iter_mut() {
+                        let fitness = function(chromosome.genes());
+                        chromosome.set_fitness(fitness);
+                    }
+                });
+            }
+        }).expect("The fitness function panicked while evaluating in parallel");
+        pending.len()
+    }
+}
+
+// Remembers the fitness of every genotype it has seen, so identical chromosomes (for example parents that
+// were passed on unchanged, or offspring that happen to recreate an earlier solution) are only evaluated
+// once during the whole run. The cache is never pruned, so it's best suited for expensive fitness functions.
+// Ranked fitness functions such as `pareto::Pareto` can't be cached, and wrapping one panics.
+pub struct Cached<'a, T, F> where F: 'a {
+    fitness: &'a F,
+    cache: RefCell<HashMap<Vec<T>, f64>>,
+    hits: Cell<usize>,
+    misses: Cell<usize>,
+}
+
+impl<'a, T, F> Cached<'a, T, F> where T: Clone + Eq + Hash, F: 'a + Evaluate<T> {
+    pub fn new(fitness: &'a F) -> Self {
+        if fitness.objective().is_some() {
+            panic!("Ranked fitness values depend on the whole generation and can't be cached");
+        }
+        Cached {
+            fitness: fitness,
+            cache: RefCell::new(HashMap::new()),
+            hits: Cell::new(0),
+            misses: Cell::new(0),
+        }
+    }
+
+    pub fn hits(&self) -> usize {
+        self.hits.get()
+    }
+
+    pub fn misses(&self) -> usize {
+        self.misses.get()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    pub fn clear(&self) {
+        self.cache.borrow_mut().clear();
+    }
+}
+
+impl<'a, T, F> Evaluate<T> for Cached<'a, T, F> where T: Clone + Eq + Hash, F: 'a + Evaluate<T> {
+    fn evaluate(&self, chromosomes: &mut [Chromosome<T>]) -> usize {
+        let mut cache = self.cache.borrow_mut();
+        let mut missed = Vec::new();
+
+        for (i, chromosome) in chromosomes.iter_mut().enumerate().filter(|&(_, ref c)| !c.is_evaluated()) {
+            if let Some(&fitness) = cache.get(chromosome.genes()) {
+                chromosome.set_fitness(fitness);
+                self.hits.set(self.hits.get() + 1);
+            } else {
+                missed.push(i);
+            }
+        }
+
+        let evaluations = self.fitness.evaluate(chromosomes);
+        for i in missed {
+            cache.insert(chromosomes[i].genes().to_vec(), chromosomes[i].fitness);
+        }
+
+        self.misses.set(self.misses.get() + evaluations);
+        evaluations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chromosome::Chromosome;
+    use evaluation::{Cached, Evaluate};
+    use objective::Objective;
+    use pareto::Pareto;
+
+    #[test]
+    fn evaluate_every_chromosome() {
+        let fitness = |genes: &[i32]| genes.iter().fold(0.0, |sum, g| sum + *g as f64);
+        let mut chromosomes = (0..100)
+            .map(|i| Chromosome::from(vec![i, i + 1, i + 2]))
+            .collect::<Vec<_>>();
+
+        assert!(fitness.evaluate(&mut chromosomes) == 100);
+        for (i, chromosome) in chromosomes.iter().enumerate() {
+            assert_approx_eq!(chromosome.fitness, 3.0 * i as f64 + 3.0, 1e-9);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn parallel_matches_serial() {
+        use evaluation::Parallel;
+
+        let fitness = |genes: &[i32]| genes.iter().fold(0.0, |sum, g| sum + *g as f64);
+        let mut serial = (0..100).map(|i| Chromosome::from(vec![i, i + 1])).collect::<Vec<_>>();
+        let mut parallel = serial.clone();
+        parallel[0].set_fitness(-1.0);
+
+        assert!(fitness.evaluate(&mut serial) == 100);
+        assert!(Parallel(&fitness).evaluate(&mut parallel) == 99);
+        for (first, second) in serial.iter().zip(&parallel).skip(1) {
+            assert_approx_eq!(first.fitness, second.fitness, 1e-9);
+        }
+        assert_approx_eq!(parallel[0].fitness, -1.0, 1e-9);
+    }
+
+    #[test]
+    fn evaluate_skips_evaluated_chromosomes() {
+        let fitness = |_: &[i32]| 1.0;
+        let mut chromosomes = vec![Chromosome::new(vec![0], 5.0), Chromosome::from(vec![1])];
+
+        assert!(fitness.evaluate(&mut chromosomes) == 1);
+        assert_approx_eq!(chromosomes[0].fitness, 5.0, 1e-9);
+        assert_approx_eq!(chromosomes[1].fitness, 1.0, 1e-9);
+
+        chromosomes[0][0] = 2;
+        assert!(!chromosomes[0].is_evaluated());
+    }
+
+    #[test]
+    fn cached_counts_hits_and_misses() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let cached = Cached::new(&fitness);
+
+        let mut first = vec![Chromosome::from(vec![0]), Chromosome::from(vec![1])];
+        let mut second = vec![Chromosome::from(vec![1]), Chromosome::from(vec![2])];
+        cached.evaluate(&mut first);
+        cached.evaluate(&mut second);
+
+        assert!(cached.hits() == 1);
+        assert!(cached.misses() == 3);
+        assert_approx_eq!(second[0].fitness, 1.0, 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cached_rejects_pareto() {
+        let fitness = |genes: &[i32]| vec![genes[0] as f64, genes[1] as f64];
+        let pareto = Pareto::new(&fitness, vec![Objective::Minimize, Objective::Maximize]);
+        Cached::<i32, _>::new(&pareto);
+    }
+}
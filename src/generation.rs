@@ -1,38 +1,42 @@
-use std::ops::Fn;
+use std::cmp;
+use std::io;
 use std::slice::Iter;
+use std::str::FromStr;
 
-use itertools::Itertools;
-
+use checkpoint::{Checkpoint, Reader, Writer, parse};
 use chromosome::Chromosome;
+use evaluation::Evaluate;
+use objective::Objective;
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Generation<T> {
     chromosomes: Vec<Chromosome<T>>,
     total_fitness: f64,
+    objective: Objective,
+    evaluations: usize,
 }
 
 impl<T> Generation<T> {
     pub fn empty() -> Self {
-        Generation { chromosomes: Vec::new(), total_fitness: 0.0 }
+        Generation::from(Vec::new())
     }
 
-    pub fn new<F, U, I>(generation: I, fitness: &F) -> Self
-        where F: Fn(&[T]) -> f64,
+    pub fn new<F, U, I>(generation: I, fitness: &F, objective: Objective) -> Self
+        where F: Evaluate<T> + ?Sized,
               U: IntoIterator<Item = T>,
               I: IntoIterator<Item = U> {
 
-        let mut chromosomes = generation.into_iter().map(|genome_data| {
+        let chromosomes = generation.into_iter().map(|genome_data| {
             let genes = genome_data
                 .into_iter()
                 .collect::<Vec<_>>();
 
-            let fitness = fitness(&genes);
-            Chromosome::new(genes, fitness)
+            Chromosome::from(genes)
         }).collect::<Vec<_>>();
 
-        chromosomes.sort_by(|c1, c2| Ord::cmp(&c1, &c2).reverse());
-        let total_fitness = chromosomes.iter().fold(0.0, |sum, c| sum + c.fitness);
-
-        Generation { chromosomes: chromosomes, total_fitness: total_fitness }
+        let mut generation = Generation::from(chromosomes);
+        generation.reevaluate(fitness, objective);
+        generation
     }
 
     pub fn best(&self) -> &Chromosome<T> {
@@ -55,11 +59,48 @@ impl<T> Generation<T> {
         self.total_fitness
     }
 
-    pub fn reevaluate<F>(&mut self, fitness: &F) where F: Fn(&[T]) -> f64  {
-        for chromosome in self.chromosomes.iter_mut() {
-                chromosome.fitness = fitness(&chromosome);
+    pub fn objective(&self) -> Objective {
+        self.objective
+    }
+
+    // The number of fitness function calls made so far, counted from the initial population of the run.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    // Counts fitness function calls that were made outside of `reevaluate`, e.g. by a reinsertion operator
+    // that has to evaluate the offspring itself.
+    pub fn add_evaluations(&mut self, evaluations: usize) {
+        self.evaluations += evaluations;
+    }
+
+    // Evaluates every chromosome that doesn't have an up to date fitness, then sorts the generation so that
+    // the best chromosome (according to the objective) comes first.
+    pub fn reevaluate<F>(&mut self, fitness: &F, objective: Objective) where F: Evaluate<T> + ?Sized {
+        if let Some(required) = fitness.objective() {
+            if required != objective {
+                panic!("The fitness function can only be used to {}", required);
+            }
         }
-        self.chromosomes.sort_by(|c1, c2| Ord::cmp(&c1, &c2).reverse());
+        self.evaluations += fitness.evaluate(&mut self.chromosomes);
+        self.objective = objective;
+        self.sort();
+    }
+
+    // Replaces the worst chromosomes with the given (already evaluated) ones, keeping the size of the
+    // generation. Used to let migrants in from other islands.
+    pub fn replace_worst(&mut self, chromosomes: Vec<Chromosome<T>>) {
+        let size = self.size();
+        let count = cmp::min(size, chromosomes.len());
+        self.chromosomes.truncate(size - count);
+        self.chromosomes.extend(chromosomes.into_iter().take(count));
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        let objective = self.objective;
+        self.chromosomes.sort_by(|c1, c2| objective.compare(c1.fitness, c2.fitness).reverse());
+        self.total_fitness = self.chromosomes.iter().fold(0.0, |sum, c| sum + c.fitness);
     }
 }
 
@@ -72,8 +113,38 @@ impl<T> Clone for Generation<T> where T: Clone {
         Generation {
             chromosomes: self.chromosomes.clone(),
             total_fitness: self.total_fitness,
+            objective: self.objective,
+            evaluations: self.evaluations,
+        }
+    }
+}
+
+impl<T> Checkpoint for Generation<T> where T: ToString + FromStr {
+    fn save(&self, writer: &mut Writer) {
+        writer.write("generation", vec![self.objective.to_string(), self.evaluations.to_string(),
+                                        self.size().to_string()]);
+        for chromosome in &self.chromosomes {
+            writer.chromosome("chromosome", chromosome);
         }
     }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("generation", Some(3)));
+        let objective = try!(parse(&values[0]));
+        let evaluations = try!(parse(&values[1]));
+        let size: usize = try!(parse(&values[2]));
+
+        let mut chromosomes = Vec::with_capacity(size);
+        for _ in 0..size {
+            chromosomes.push(try!(reader.chromosome("chromosome")));
+        }
+
+        self.chromosomes = chromosomes;
+        self.objective = objective;
+        self.evaluations = evaluations;
+        self.sort();
+        Ok(())
+    }
 }
 
 impl<T> From<Vec<Chromosome<T>>> for Generation<T> {
@@ -81,6 +152,38 @@ impl<T> From<Vec<Chromosome<T>>> for Generation<T> {
         Generation {
             chromosomes: chromosomes,
             total_fitness: 0.0,
+            objective: Objective::Maximize,
+            evaluations: 0,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use generation::Generation;
+    use objective::Objective;
+
+    #[test]
+    fn generation_minimize_sorts_ascending() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let population = vec![vec![2], vec![0], vec![3], vec![1]];
+        let generation = Generation::new(population, &fitness, Objective::Minimize);
+
+        let fitnesses = generation.iter().map(|c| c.fitness).collect::<Vec<_>>();
+        assert!(fitnesses == vec![0.0, 1.0, 2.0, 3.0]);
+        assert!(generation.best().fitness == 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn generation_serde_round_trip() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let generation = Generation::new(vec![vec![2, 0], vec![1, 3]], &fitness, Objective::Minimize);
+        let json = ::serde_json::to_string(&generation).unwrap();
+        let restored: Generation<i32> = ::serde_json::from_str(&json).unwrap();
+
+        assert!(restored.objective() == Objective::Minimize && restored.evaluations() == 2);
+        assert!(restored.best().genes() == &[1, 3] && restored.total_fitness() == 3.0);
+        assert!(restored.iter().map(|c| c.genes()).eq(generation.iter().map(|c| c.genes())));
+    }
+}
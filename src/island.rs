@@ -0,0 +1,193 @@
+use rand::Rng;
+
+use chromosome::Chromosome;
+use generation::Generation;
+use random::Xorshift128;
+use termination::Termination;
+
+// A sub-population that can be evolved one generation at a time and exchange chromosomes with other
+// sub-populations. Implemented by `Algorithm`, so every island can use its own operators.
+pub trait Island<T> {
+    fn initialize(&mut self, population: Vec<Vec<T>>);
+
+    fn advance(&mut self);
+
+    fn generation(&self) -> &Generation<T>;
+
+    // Lets the migrants replace the worst chromosomes of the island.
+    fn immigrate(&mut self, migrants: Vec<Chromosome<T>>);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Topology {
+    /// Every island sends its migrants to the next one, the last island sends them to the first.
+    Ring,
+    /// Every island sends its migrants to all other islands.
+    FullyConnected,
+    /// Every island sends its migrants to another island chosen at random.
+    Random,
+}
+
+pub struct Archipelago<'a, T, G = Xorshift128> {
+    islands: Vec<Box<Island<T> + 'a>>,
+    topology: Topology,
+    interval: usize,
+    migrants: usize,
+    rng: G,
+}
+
+impl<'a, T> Archipelago<'a, T, Xorshift128> where T: Clone {
+    // Migrates the best `migrants` chromosomes of every island every `interval` generations.
+    pub fn new(topology: Topology, interval: usize, migrants: usize) -> Self {
+        if interval == 0 {
+            panic!("The migration interval must be at least 1");
+        }
+        Archipelago {
+            islands: Vec::new(),
+            topology: topology,
+            interval: interval,
+            migrants: migrants,
+            rng: Xorshift128::new(),
+        }
+    }
+}
+
+impl<'a, T, G> Archipelago<'a, T, G> where T: Clone, G: Rng {
+    pub fn island<I>(mut self, island: I) -> Self where I: 'a + Island<T> {
+        self.islands.push(Box::new(island));
+        self
+    }
+
+    // Replaces the random number generator used to pick the destinations of `Topology::Random`.
+    pub fn rng<H>(self, rng: H) -> Archipelago<'a, T, H> where H: Rng {
+        Archipelago {
+            islands: self.islands,
+            topology: self.topology,
+            interval: self.interval,
+            migrants: self.migrants,
+            rng: rng,
+        }
+    }
+
+    pub fn islands(&self) -> &[Box<Island<T> + 'a>] {
+        &self.islands
+    }
+
+    // Evolves every island from its own initial population. The termination condition is checked against
+    // the generation of the island that holds the best chromosome.
+    pub fn evolve<Term>(&mut self, populations: Vec<Vec<Vec<T>>>, mut termination: Term)
+        where Term: Termination<T> {
+
+        if populations.len() != self.islands.len() {
+            panic!("Every island needs exactly one initial population");
+        }
+
+        for (island, population) in self.islands.iter_mut().zip(populations) {
+            island.initialize(population);
+        }
+
+        let mut elapsed = 0;
+        while !termination.reached(self.islands[self.best_island()].generation()) {
+            for island in self.islands.iter_mut() {
+                island.advance();
+            }
+
+            elapsed += 1;
+            if elapsed % self.interval == 0 {
+                self.migrate();
+            }
+        }
+    }
+
+    pub fn best(&self) -> (Vec<T>, f64) {
+        let best = self.islands[self.best_island()].generation().best();
+        (Vec::from(best.genes()), best.fitness)
+    }
+
+    fn best_island(&self) -> usize {
+        let mut best = 0;
+        for (i, island) in self.islands.iter().enumerate().skip(1) {
+            let generation = island.generation();
+            let current = self.islands[best].generation().best().fitness;
+            if generation.objective().is_better(generation.best().fitness, current) {
+                best = i;
+            }
+        }
+        best
+    }
+
+    fn migrate(&mut self) {
+        let count = self.islands.len();
+        if count < 2 {
+            return;
+        }
+
+        let mut arrivals = (0..count).map(|_| Vec::new()).collect::<Vec<_>>();
+        for source in 0..count {
+            let migrants = {
+                let generation = self.islands[source].generation();
+                generation.top(::std::cmp::min(self.migrants, generation.size())).to_vec()
+            };
+
+            match self.topology {
+                Topology::Ring => arrivals[(source + 1) % count].extend(migrants),
+                Topology::FullyConnected => {
+                    for target in (0..count).filter(|&target| target != source) {
+                        arrivals[target].extend(migrants.iter().cloned());
+                    }
+                },
+                Topology::Random => {
+                    let mut target = self.rng.gen_range(0, count - 1);
+                    if target >= source {
+                        target += 1;
+                    }
+                    arrivals[target].extend(migrants);
+                },
+            }
+        }
+
+        for (island, migrants) in self.islands.iter_mut().zip(arrivals) {
+            island.immigrate(migrants);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use algorithm::Algorithm;
+    use crossover::PartiallyMapped;
+    use island::{Archipelago, Island, Topology};
+    use mutation::Shuffle;
+    use problem::{Problem, Permutation};
+    use random::Xorshift128;
+    use reinsertion::Elitist;
+    use selection::Rank;
+    use termination::Iterations;
+    use tracking::BestSolution;
+    use utility::weighted_sum;
+
+    #[test]
+    fn archipelago_ring_migration() {
+        let mut rng = Xorshift128::from_seed([1, 2, 3, 4]);
+        let problem = Permutation::from(0..10usize);
+
+        let first = test_algorithm!(&weighted_sum, BestSolution::new());
+        let second = Algorithm::new(&weighted_sum, Rank::linear(0.9, 1.5), (PartiallyMapped::new(), 0.8),
+                                    (Shuffle::new(), 0.2), Elitist::new(), BestSolution::new());
+        let mut archipelago = Archipelago::new(Topology::Ring, 5, 2).island(first).island(second);
+
+        let populations = vec![problem.generate_population(20, &mut rng),
+                               problem.generate_population(20, &mut rng)];
+        archipelago.evolve(populations, Iterations::new(20));
+
+        // Migration happens right after the last generation, so both islands end up with the overall best.
+        let (_, best) = archipelago.best();
+        for island in archipelago.islands() {
+            assert!(island.generation().size() == 20);
+            assert!(island.generation().best().fitness == best);
+        }
+    }
+}
@@ -4,10 +4,23 @@
 
 
 extern crate approx;
+#[cfg(feature = "parallel")]
+extern crate crossbeam;
 extern crate itertools;
 extern crate linear_map;
+#[cfg(feature = "parallel")]
+extern crate num_cpus;
 extern crate rand;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde_derive;
+#[cfg(feature = "serde")]
+extern crate serde_json;
 extern crate test;
+#[cfg(feature = "config")]
+extern crate toml;
 
 #[macro_use]
 mod utility;
@@ -18,17 +31,33 @@ mod generation;
 #[macro_use]
 pub mod algorithm;
 
+pub mod cancellation;
+pub mod checkpoint;
+#[cfg(feature = "config")]
+pub mod config;
 pub mod crossover;
+pub mod diversity;
+pub mod evaluation;
+pub mod island;
 pub mod mutation;
+pub mod objective;
+pub mod observer;
+pub mod pareto;
 pub mod problem;
+pub mod random;
 pub mod reinsertion;
+pub mod schedule;
 pub mod selection;
 pub mod termination;
 pub mod tracking;
 
 pub use algorithm::Algorithm;
+pub use chromosome::Chromosome;
 pub use crossover::Crossover;
+pub use evaluation::Evaluate;
+pub use generation::Generation;
 pub use mutation::Mutation;
+pub use objective::Objective;
 pub use problem::{Problem, UniformProblem, Numeric, Permutation};
 pub use reinsertion::Reinsertion;
 pub use selection::Selection;
@@ -1,9 +1,10 @@
-use rand;
+use rand::Rng;
 
 use mutation::Mutation;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CentreInverse {
     preset_split: Option<usize>,
 }
@@ -19,8 +20,7 @@ impl CentreInverse {
 }
 
 impl<T> Mutation<T> for CentreInverse where T: Clone {
-    fn mutate(&self, mut genes: Vec<T>, mutation_rate: f64) -> Vec<T> {
-        let mut rng = rand::thread_rng();
+    fn mutate<R>(&self, mut genes: Vec<T>, mutation_rate: f64, rng: &mut R) -> Vec<T> where R: Rng {
         if rng.happens(mutation_rate) {
             let length = genes.len();
             let split = self.preset_split.unwrap_or(rng.index(&genes));
@@ -29,6 +29,10 @@ impl<T> Mutation<T> for CentreInverse where T: Clone {
         }
         genes
     }
+
+    fn name(&self) -> &'static str {
+        "CentreInverse"
+    }
 }
 
 #[cfg(test)]
@@ -41,6 +45,6 @@ mod tests {
         let mutation = CentreInverse::with_preset_split(4);
         let genes = vec![1, 2, 3, 4, 5, 6];
         let expected = vec![4, 3, 2, 1, 6, 5];
-        assert!(expected == mutation.mutate(genes, 1.0));
+        assert!(expected == mutation.mutate(genes, 1.0, &mut ::rand::thread_rng()));
     }
 }
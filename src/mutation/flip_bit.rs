@@ -1,10 +1,11 @@
 use std::ops::Not;
-use rand;
+use rand::Rng;
 
 use mutation::Mutation;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FlipBit;
 
 impl FlipBit {
@@ -14,12 +15,15 @@ impl FlipBit {
 }
 
 impl<T> Mutation<T> for FlipBit where T: Clone + Not<Output = T> {
-    fn mutate(&self, mut genes: Vec<T>, mutation_rate: f64) -> Vec<T> {
-        let mut rng = rand::thread_rng();
+    fn mutate<R>(&self, mut genes: Vec<T>, mutation_rate: f64, rng: &mut R) -> Vec<T> where R: Rng {
         if rng.happens(mutation_rate) {
             let position = rng.index(&genes);
             genes[position] = !(genes[position].clone());
         }
         genes
     }
+
+    fn name(&self) -> &'static str {
+        "FlipBit"
+    }
 }
@@ -7,6 +7,10 @@ mod thrors;
 mod twors;
 mod uniform;
 
+use rand::Rng;
+
+use utility::short_type_name;
+
 pub use self::centre_inverse::CentreInverse;
 pub use self::flip_bit::FlipBit;
 pub use self::partial_shuffle::PartialShuffle;
@@ -17,5 +21,39 @@ pub use self::twors::Twors;
 pub use self::uniform::UniformMutation;
 
 pub trait Mutation<T> {
-    fn mutate(&self, genes: Vec<T>, mutation_rate: f64) -> Vec<T>;
+    fn mutate<R>(&self, genes: Vec<T>, mutation_rate: f64, rng: &mut R) -> Vec<T> where R: Rng;
+
+    // The name of the operator, which is recorded in the genealogy of the chromosomes it changes. The
+    // default is derived from the type name, which isn't guaranteed to be stable, so operators should
+    // override it.
+    fn name(&self) -> &'static str {
+        short_type_name::<Self>()
+    }
+}
+
+// An object safe version of `Mutation`, implemented for every mutation operator. Boxed operators implement
+// `Mutation` again.
+pub trait DynMutation<T> {
+    fn dyn_mutate(&self, genes: Vec<T>, mutation_rate: f64, rng: &mut Rng) -> Vec<T>;
+    fn dyn_name(&self) -> &'static str;
+}
+
+impl<T, M> DynMutation<T> for M where M: Mutation<T> {
+    fn dyn_mutate(&self, genes: Vec<T>, mutation_rate: f64, mut rng: &mut Rng) -> Vec<T> {
+        self.mutate(genes, mutation_rate, &mut rng)
+    }
+
+    fn dyn_name(&self) -> &'static str {
+        self.name()
+    }
+}
+
+impl<'a, T> Mutation<T> for Box<DynMutation<T> + 'a> {
+    fn mutate<R>(&self, genes: Vec<T>, mutation_rate: f64, rng: &mut R) -> Vec<T> where R: Rng {
+        (**self).dyn_mutate(genes, mutation_rate, rng)
+    }
+
+    fn name(&self) -> &'static str {
+        (**self).dyn_name()
+    }
 }
@@ -1,9 +1,10 @@
-use rand::{self, Rng};
+use rand::Rng;
 
 use mutation::Mutation;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PartialShuffle {
     n: usize,
 }
@@ -15,8 +16,7 @@ impl PartialShuffle {
 }
 
 impl<T> Mutation<T> for PartialShuffle where T: Clone {
-    fn mutate(&self, mut genes: Vec<T>, mutation_rate: f64) -> Vec<T> {
-        let mut rng = rand::thread_rng();
+    fn mutate<R>(&self, mut genes: Vec<T>, mutation_rate: f64, rng: &mut R) -> Vec<T> where R: Rng {
         for i in 0..self.n {
             if rng.happens(mutation_rate) {
                 let j = rng.gen_range(0, self.n);
@@ -25,4 +25,8 @@ impl<T> Mutation<T> for PartialShuffle where T: Clone {
         }
         genes
     }
+
+    fn name(&self) -> &'static str {
+        "PartialShuffle"
+    }
 }
@@ -1,10 +1,11 @@
 use std::ops::Range;
-use rand::{self, Rng};
+use rand::Rng;
 
 use mutation::Mutation;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ReverseSequence {
     preset_split: Option<(usize, usize)>,
 }
@@ -23,8 +24,7 @@ impl ReverseSequence {
 }
 
 impl<T> Mutation<T> for ReverseSequence where T: Clone {
-    fn mutate(&self, mut genes: Vec<T>, mutation_rate: f64) -> Vec<T> {
-        let mut rng = rand::thread_rng();
+    fn mutate<R>(&self, mut genes: Vec<T>, mutation_rate: f64, rng: &mut R) -> Vec<T> where R: Rng {
         if rng.happens(mutation_rate) {
             let length = genes.len();
             let (from, to) = match self.preset_split {
@@ -39,6 +39,10 @@ impl<T> Mutation<T> for ReverseSequence where T: Clone {
         }
         genes
     }
+
+    fn name(&self) -> &'static str {
+        "ReverseSequence"
+    }
 }
 
 #[cfg(test)]
@@ -51,6 +55,6 @@ mod tests {
         let mutation = ReverseSequence::with_preset_split(1..5);
         let genes = vec![1, 2, 3, 4, 5, 6];
         let expected = vec![1, 5, 4, 3, 2, 6];
-        assert!(expected == mutation.mutate(genes, 1.0));
+        assert!(expected == mutation.mutate(genes, 1.0, &mut ::rand::thread_rng()));
     }
 }
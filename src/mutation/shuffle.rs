@@ -1,9 +1,10 @@
-use rand::{self, Rng};
+use rand::Rng;
 
 use mutation::Mutation;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Shuffle;
 
 impl Shuffle {
@@ -13,11 +14,14 @@ impl Shuffle {
 }
 
 impl<T> Mutation<T> for Shuffle where T: Clone {
-    fn mutate(&self, mut genes: Vec<T>, mutation_rate: f64) -> Vec<T> {
-        let mut rng = rand::thread_rng();
+    fn mutate<R>(&self, mut genes: Vec<T>, mutation_rate: f64, rng: &mut R) -> Vec<T> where R: Rng {
         if rng.happens(mutation_rate) {
             rng.shuffle(genes.as_mut());
         }
         genes
     }
+
+    fn name(&self) -> &'static str {
+        "Shuffle"
+    }
 }
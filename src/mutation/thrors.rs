@@ -1,10 +1,11 @@
-use rand::{self, Rng};
+use rand::Rng;
 use itertools::Itertools;
 
 use mutation::Mutation;
 use utility::{Gather, RngExt};
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Thrors;
 
 impl Thrors {
@@ -14,11 +15,10 @@ impl Thrors {
 }
 
 impl<T> Mutation<T> for Thrors where T: Clone {
-    fn mutate(&self, mut genes: Vec<T>, mutation_rate: f64) -> Vec<T> {
-        let mut rng = rand::thread_rng();
+    fn mutate<R>(&self, mut genes: Vec<T>, mutation_rate: f64, rng: &mut R) -> Vec<T> where R: Rng {
         if rng.happens(mutation_rate) {
             let length = genes.len();
-            unique_samples!(0, length).take(3).triples()
+            unique_samples!(0, length, rng).take(3).triples()
                 .foreach(|(i, j, k)| {
                     (*genes).as_mut().swap(i, j);
                     (*genes).as_mut().swap(i, k);
@@ -26,4 +26,8 @@ impl<T> Mutation<T> for Thrors where T: Clone {
         }
         genes
     }
+
+    fn name(&self) -> &'static str {
+        "Thrors"
+    }
 }
@@ -1,9 +1,10 @@
-use rand;
+use rand::Rng;
 
 use mutation::Mutation;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Twors;
 
 impl Twors {
@@ -13,12 +14,15 @@ impl Twors {
 }
 
 impl<T> Mutation<T> for Twors where T: Clone {
-    fn mutate(&self, mut genes: Vec<T>, mutation_rate: f64) -> Vec<T> {
-        let mut rng = rand::thread_rng();
+    fn mutate<R>(&self, mut genes: Vec<T>, mutation_rate: f64, rng: &mut R) -> Vec<T> where R: Rng {
         if rng.happens(mutation_rate) {
             let (p0, p1) = rng.range_indexes(&genes);
             (*genes).as_mut().swap(p0, p1);
         }
         genes
     }
+
+    fn name(&self) -> &'static str {
+        "Twors"
+    }
 }
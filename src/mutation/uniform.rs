@@ -1,11 +1,12 @@
 use std::marker::PhantomData;
-use rand;
+use rand::Rng;
 
 use mutation::Mutation;
 use problem::UniformProblem;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UniformMutation<T, P> where T: Clone, P: UniformProblem<T> {
     problem: P,
     _gene: PhantomData<T>,
@@ -21,13 +22,16 @@ impl<T, P> UniformMutation<T, P> where T: Clone, P: UniformProblem<T> {
 }
 
 impl<T, P> Mutation<T> for UniformMutation<T, P> where T: Clone, P: UniformProblem<T> {
-    fn mutate(&self, mut genes: Vec<T>, mutation_rate: f64) -> Vec<T> {
-        let mut rng = rand::thread_rng();
+    fn mutate<R>(&self, mut genes: Vec<T>, mutation_rate: f64, rng: &mut R) -> Vec<T> where R: Rng {
         for (i, c) in genes.iter_mut().enumerate() {
             if rng.happens(mutation_rate) {
-                *c = self.problem.generate_gene(i)
+                *c = self.problem.generate_gene(i, rng)
             }
         }
         genes
     }
+
+    fn name(&self) -> &'static str {
+        "UniformMutation"
+    }
 }
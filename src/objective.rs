@@ -0,0 +1,63 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Objective {
+    Maximize,
+    Minimize,
+}
+
+impl Objective {
+    // Compares two fitness values so that the better one is considered greater.
+    pub fn compare(&self, first: f64, second: f64) -> Ordering {
+        let ordering = first.partial_cmp(&second)
+            .expect("A Chromosome's fitness value may never be NaN");
+        match *self {
+            Objective::Maximize => ordering,
+            Objective::Minimize => ordering.reverse(),
+        }
+    }
+
+    pub fn is_better(&self, first: f64, second: f64) -> bool {
+        self.compare(first, second) == Ordering::Greater
+    }
+
+    // Maps a fitness value to a score where higher is always better, which is what fitness proportionate
+    // operators need.
+    pub fn score(&self, fitness: f64) -> f64 {
+        match *self {
+            Objective::Maximize => fitness,
+            Objective::Minimize => -fitness,
+        }
+    }
+}
+
+impl fmt::Display for Objective {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Objective::Maximize => write!(f, "maximize"),
+            Objective::Minimize => write!(f, "minimize"),
+        }
+    }
+}
+
+impl FromStr for Objective {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "maximize" => Ok(Objective::Maximize),
+            "minimize" => Ok(Objective::Minimize),
+            _ => Err(format!("Unknown objective `{}`", s)),
+        }
+    }
+}
+
+impl Default for Objective {
+    fn default() -> Self {
+        Objective::Maximize
+    }
+}
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use generation::Generation;
+use tracking::Record;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Kind {
+    // The initial population was evaluated.
+    RunStarted,
+    // A new generation is about to be produced from the one in the event.
+    GenerationStarted,
+    // A new generation was produced and registered with the tracker.
+    GenerationFinished,
+    // The best chromosome of the generation is better than every earlier one.
+    NewBest,
+    // The best fitness hasn't improved for the given number of generations (see `Algorithm::stagnation`).
+    Stagnation(usize),
+    // The run ended, either because the termination condition was reached or because an observer stopped it.
+    RunFinished,
+}
+
+// What's passed to an observer. `index` is the index of the generation in the run, where the initial
+// population is generation 0. For `GenerationStarted` it's the index of the generation that's about to be
+// produced, while `generation` is still its parent generation.
+pub struct Event<'a, T> where T: 'a {
+    pub kind: Kind,
+    pub index: usize,
+    pub elapsed: Duration,
+    pub generation: &'a Generation<T>,
+}
+
+impl<'a, T> Event<'a, T> {
+    // Computing the statistics takes time quadratic in the size of the generation, so they're only computed
+    // on demand.
+    pub fn statistics(&self) -> Record where T: PartialEq {
+        Record::new(self.generation, self.index, self.elapsed)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Flow {
+    Continue,
+    // Stops `evolve` (or `resume`) before the next generation is produced.
+    Stop,
+}
+
+// Observers are notified of everything that happens during a run, e.g. to report progress or to stop the
+// run from the outside. Every closure taking an event and returning a `Flow` is an observer.
+pub trait Observer<T> {
+    fn notify(&mut self, event: &Event<T>) -> Flow;
+}
+
+impl<T, F> Observer<T> for F where F: FnMut(&Event<T>) -> Flow {
+    fn notify(&mut self, event: &Event<T>) -> Flow {
+        self(event)
+    }
+}
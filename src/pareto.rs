@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+use std::f64;
+
+use chromosome::Chromosome;
+use evaluation::Evaluate;
+use objective::Objective;
+
+// Multi-objective evaluation in the style of NSGA-II. The fitness function returns one value per objective,
+// and every objective can be maximized or minimized independently. After evaluating the new chromosomes the
+// whole generation is sorted into Pareto fronts, and the crowding distance of every chromosome within its
+// front is computed.
+//
+// The scalar fitness of a chromosome is then derived from its front and crowding distance, so that a higher
+// fitness is always better: chromosomes in a lower front come first, and within a front the ones in less
+// crowded regions come first. That means the algorithm's objective has to be left at `Objective::Maximize`
+// (evaluating a generation with any other objective panics), and every single objective operator
+// (tournaments, elitism, etc.) keeps working on the ranked generation.
+pub struct Pareto<'a, F> where F: 'a {
+    fitness: &'a F,
+    objectives: Vec<Objective>,
+}
+
+// The objective values of a chromosome and its rank within the generation, which are assigned by `Pareto`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParetoRank {
+    pub objectives: Vec<f64>,
+    pub front: usize,
+    pub crowding: f64,
+}
+
+impl<'a, F> Pareto<'a, F> {
+    pub fn new(fitness: &'a F, objectives: Vec<Objective>) -> Self {
+        if objectives.is_empty() {
+            panic!("At least one objective is required");
+        }
+        Pareto {
+            fitness: fitness,
+            objectives: objectives,
+        }
+    }
+
+    pub fn objectives(&self) -> &[Objective] {
+        &self.objectives
+    }
+}
+
+impl<'a, T, F> Evaluate<T> for Pareto<'a, F> where F: 'a + Fn(&[T]) -> Vec<f64> {
+    fn evaluate(&self, chromosomes: &mut [Chromosome<T>]) -> usize {
+        let mut evaluations = 0;
+        for chromosome in chromosomes.iter_mut().filter(|c| !c.is_evaluated()) {
+            let objectives = (self.fitness)(chromosome.genes());
+            if objectives.len() != self.objectives.len() {
+                panic!("The fitness function returned {} objectives, expected {}",
+                       objectives.len(), self.objectives.len());
+            }
+            chromosome.pareto = Some(ParetoRank { objectives: objectives, front: 0, crowding: 0.0 });
+            chromosome.set_fitness(0.0);
+            evaluations += 1;
+        }
+
+        rank(chromosomes, &self.objectives);
+        evaluations
+    }
+
+    fn objective(&self) -> Option<Objective> {
+        Some(Objective::Maximize)
+    }
+}
+
+// Whether `first` is at least as good as `second` in every objective, and strictly better in at least one.
+pub fn dominates(first: &[f64], second: &[f64], objectives: &[Objective]) -> bool {
+    let mut better = false;
+    for ((a, b), objective) in first.iter().zip(second).zip(objectives) {
+        match objective.compare(*a, *b) {
+            Ordering::Less => return false,
+            Ordering::Greater => better = true,
+            Ordering::Equal => {},
+        }
+    }
+    better
+}
+
+// The crowded comparison operator of NSGA-II, where the better chromosome is considered greater.
+pub fn crowded_compare<T>(first: &Chromosome<T>, second: &Chromosome<T>) -> Ordering {
+    let (first, second) = (pareto_rank(first), pareto_rank(second));
+    match second.front.cmp(&first.front) {
+        Ordering::Equal => first.crowding.partial_cmp(&second.crowding).unwrap_or(Ordering::Equal),
+        ordering => ordering,
+    }
+}
+
+fn pareto_rank<T>(chromosome: &Chromosome<T>) -> &ParetoRank {
+    match chromosome.pareto {
+        Some(ref rank) => rank,
+        None => panic!("Only chromosomes evaluated by `pareto::Pareto` have a Pareto rank"),
+    }
+}
+
+// Fast non-dominated sorting, which assigns the front (starting at 0) and crowding distance of every
+// chromosome and sets its fitness accordingly.
+fn rank<T>(chromosomes: &mut [Chromosome<T>], objectives: &[Objective]) {
+    let mut ranks = chromosomes.iter_mut()
+        .map(|c| c.pareto.take().expect("Every chromosome has to be evaluated by the same `Pareto`"))
+        .collect::<Vec<_>>();
+    let size = ranks.len();
+    let mut dominated = vec![Vec::new(); size];
+    let mut counts = vec![0; size];
+
+    for i in 0..size {
+        for j in (i + 1)..size {
+            if dominates(&ranks[i].objectives, &ranks[j].objectives, objectives) {
+                dominated[i].push(j);
+                counts[j] += 1;
+            } else if dominates(&ranks[j].objectives, &ranks[i].objectives, objectives) {
+                dominated[j].push(i);
+                counts[i] += 1;
+            }
+        }
+    }
+
+    let mut current = (0..size).filter(|&i| counts[i] == 0).collect::<Vec<_>>();
+    let mut front = 0;
+    while !current.is_empty() {
+        assign_crowding(&mut ranks, &current, objectives.len());
+
+        let mut next = Vec::new();
+        for &i in &current {
+            ranks[i].front = front;
+            for &j in &dominated[i] {
+                counts[j] -= 1;
+                if counts[j] == 0 {
+                    next.push(j);
+                }
+            }
+        }
+
+        front += 1;
+        current = next;
+    }
+
+    // The crowding term stays within (-0.5, 0], so no chromosome can overtake one from a better front.
+    for (chromosome, rank) in chromosomes.iter_mut().zip(ranks) {
+        let fitness = -(rank.front as f64) - 0.5 / (1.0 + rank.crowding);
+        chromosome.pareto = Some(rank);
+        chromosome.set_fitness(fitness);
+    }
+}
+
+fn assign_crowding(ranks: &mut [ParetoRank], front: &[usize], objectives: usize) {
+    for &i in front {
+        ranks[i].crowding = 0.0;
+    }
+
+    for m in 0..objectives {
+        let mut sorted = front.to_vec();
+        sorted.sort_by(|&a, &b| {
+            ranks[a].objectives[m].partial_cmp(&ranks[b].objectives[m])
+                .expect("An objective value may never be NaN")
+        });
+
+        let (first, last) = (sorted[0], sorted[sorted.len() - 1]);
+        let range = ranks[last].objectives[m] - ranks[first].objectives[m];
+        ranks[first].crowding = f64::INFINITY;
+        ranks[last].crowding = f64::INFINITY;
+        if range <= 0.0 {
+            continue;
+        }
+
+        for window in sorted.windows(3) {
+            let distance = ranks[window[2]].objectives[m] - ranks[window[0]].objectives[m];
+            ranks[window[1]].crowding += distance / range;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    use chromosome::Chromosome;
+    use evaluation::Evaluate;
+    use generation::Generation;
+    use objective::Objective;
+    use pareto::{Pareto, dominates};
+
+    #[test]
+    fn pareto_dominance() {
+        let objectives = [Objective::Minimize, Objective::Maximize];
+
+        assert!(dominates(&[1.0, 2.0], &[2.0, 2.0], &objectives));
+        assert!(!dominates(&[1.0, 2.0], &[1.0, 2.0], &objectives));
+        assert!(!dominates(&[1.0, 1.0], &[2.0, 2.0], &objectives));
+    }
+
+    #[test]
+    fn pareto_fronts_and_crowding() {
+        let fitness = |genes: &[i32]| vec![genes[0] as f64, genes[1] as f64];
+        let pareto = Pareto::new(&fitness, vec![Objective::Minimize, Objective::Minimize]);
+        let mut chromosomes = vec![vec![0, 4], vec![1, 2], vec![4, 0], vec![2, 3], vec![4, 4]].into_iter()
+            .map(Chromosome::from)
+            .collect::<Vec<_>>();
+
+        assert!(pareto.evaluate(&mut chromosomes) == 5);
+
+        let ranks = chromosomes.iter().map(|c| c.pareto.clone().unwrap()).collect::<Vec<_>>();
+        assert!(ranks.iter().map(|rank| rank.front).collect::<Vec<_>>() == vec![0, 0, 0, 1, 2]);
+        assert!(ranks[0].crowding == f64::INFINITY);
+        assert_approx_eq!(ranks[1].crowding, 2.0, 1e-9);
+        assert!(chromosomes[0].fitness > chromosomes[1].fitness);
+        assert!(chromosomes[1].fitness > chromosomes[3].fitness);
+        assert!(chromosomes[3].fitness > chromosomes[4].fitness);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pareto_requires_maximize() {
+        let fitness = |genes: &[i32]| vec![genes[0] as f64, genes[1] as f64];
+        let pareto = Pareto::new(&fitness, vec![Objective::Minimize, Objective::Minimize]);
+        Generation::new(vec![vec![0, 1], vec![1, 0]], &pareto, Objective::Minimize);
+    }
+}
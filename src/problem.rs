@@ -4,49 +4,95 @@ use itertools::RepeatCall;
 use rand::Rng;
 use rand::distributions::IndependentSample;
 use rand::distributions::range::{Range, SampleRange};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
 pub trait Problem<T> : Clone where T: Clone {
-    fn generate_population(&self, size: usize) -> Vec<Vec<T>>;
+    fn generate_population<R>(&self, size: usize, rng: &mut R) -> Vec<Vec<T>> where R: Rng;
 }
 
 pub trait UniformProblem<T> : Problem<T> where T: Clone {
-    fn generate_gene(&self, index: usize) -> T;
+    fn generate_gene<R>(&self, index: usize, rng: &mut R) -> T where R: Rng;
 }
 
+// Only the bounds are stored, the `Range` used to sample genes is created from them when needed.
 #[derive(Clone, Copy)]
 pub struct Numeric<T> where T: Clone + Copy + PartialOrd + SampleRange {
     pub length: usize,
-    pub range: Range<T>,
+    low: T,
+    high: T,
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Permutation<T> where T: Clone {
     pub values: Vec<T>,
 }
 
 impl<T> Numeric<T> where T: Clone + Copy + PartialOrd + SampleRange {
     pub fn new(length: usize, (low, high): (T, T)) -> Self {
+        if !(low < high) {
+            panic!("The lower bound of a Numeric problem must be below the upper bound");
+        }
         Numeric {
             length: length,
-            range: Range::new(low, high),
+            low: low,
+            high: high,
         }
     }
+
+    pub fn bounds(&self) -> (T, T) {
+        (self.low, self.high)
+    }
+
+    pub fn range(&self) -> Range<T> {
+        Range::new(self.low, self.high)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct NumericData<T> {
+    length: usize,
+    low: T,
+    high: T,
+}
+
+#[cfg(feature = "serde")]
+impl<T> Serialize for Numeric<T> where T: Clone + Copy + PartialOrd + SampleRange + Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        let data = NumericData { length: self.length, low: self.low, high: self.high };
+        data.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for Numeric<T>
+    where T: Clone + Copy + PartialOrd + SampleRange + Deserialize<'de> {
+
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        let data = try!(NumericData::deserialize(deserializer));
+        if !(data.low < data.high) {
+            let message = "the lower bound of a Numeric problem must be below the upper bound";
+            return Err(de::Error::custom(message));
+        }
+        Ok(Numeric::new(data.length, (data.low, data.high)))
+    }
 }
 
 impl<T> Problem<T> for Numeric<T> where T: Clone + Copy + PartialOrd + SampleRange {
-    fn generate_population(&self, size: usize) -> Vec<Vec<T>> {
-        let mut rng = ::rand::thread_rng();
+    fn generate_population<R>(&self, size: usize, rng: &mut R) -> Vec<Vec<T>> where R: Rng {
+        let range = self.range();
         let chromosomes = RepeatCall::new(|| {
-            RepeatCall::new(|| self.range.ind_sample(&mut rng)).take(self.length).collect()
+            RepeatCall::new(|| range.ind_sample(rng)).take(self.length).collect()
         });
         chromosomes.take(size).collect()
     }
 }
 
 impl<T> UniformProblem<T> for Numeric<T> where T: Clone + Copy + PartialOrd + SampleRange {
-    fn generate_gene(&self, _: usize) -> T {
-        let mut rng = ::rand::thread_rng();
-        self.range.ind_sample(&mut rng)
+    fn generate_gene<R>(&self, _: usize, rng: &mut R) -> T where R: Rng {
+        self.range().ind_sample(rng)
     }
 }
 
@@ -91,14 +137,40 @@ from_integer_range!(usize);
 
 
 impl<T> Problem<T> for Permutation<T> where T: Clone {
-    fn generate_population(&self, size: usize) -> Vec<Vec<T>> {
+    fn generate_population<R>(&self, size: usize, rng: &mut R) -> Vec<Vec<T>> where R: Rng {
         let chromosomes = RepeatCall::new(|| {
             let mut genes = self.values.clone();
-            ::rand::thread_rng().shuffle(&mut genes);
+            rng.shuffle(&mut genes);
             genes
         });
         chromosomes.take(size).collect()
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use problem::{Numeric, Problem};
+    use random::Xorshift128;
 
+    #[test]
+    fn numeric_samples_within_bounds() {
+        let mut rng = Xorshift128::from_seed([1, 2, 3, 4]);
+        let population = Numeric::new(5, (-1.0, 1.0)).generate_population(10, &mut rng);
+        assert!(population.iter().all(|genes| genes.len() == 5));
+        assert!(population.iter().flat_map(|genes| genes).all(|&g| g >= -1.0 && g < 1.0));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn numeric_serde_round_trip() {
+        let numeric = Numeric::new(3, (-2.5, 4.0));
+        let json = ::serde_json::to_string(&numeric).unwrap();
+        let restored: Numeric<f64> = ::serde_json::from_str(&json).unwrap();
+        assert!(restored.length == 3 && restored.bounds() == (-2.5, 4.0));
+
+        let invalid = r#"{"length": 3, "low": 1.0, "high": 0.0}"#;
+        assert!(::serde_json::from_str::<Numeric<f64>>(invalid).is_err());
+    }
+}
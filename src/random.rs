@@ -0,0 +1,93 @@
+use std::io;
+
+use rand::{self, Rng, SeedableRng};
+
+use checkpoint::{Checkpoint, Reader, Writer, parse};
+
+// The xorshift128 generator, the same algorithm as `rand::XorShiftRng`. Unlike that one its state can be
+// saved in a checkpoint, which is why it's the default generator of `Algorithm`. Not suitable for
+// cryptographic purposes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Xorshift128 {
+    x: u32,
+    y: u32,
+    z: u32,
+    w: u32,
+}
+
+impl Xorshift128 {
+    // Creates a generator seeded from the thread local random number generator.
+    pub fn new() -> Self {
+        let mut seed = [0u32; 4];
+        while seed == [0, 0, 0, 0] {
+            seed = rand::thread_rng().gen();
+        }
+        Xorshift128::from_seed(seed)
+    }
+}
+
+impl Rng for Xorshift128 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let x = self.x;
+        let t = x ^ (x << 11);
+        self.x = self.y;
+        self.y = self.z;
+        self.z = self.w;
+        let w = self.w;
+        self.w = w ^ (w >> 19) ^ (t ^ (t >> 8));
+        self.w
+    }
+}
+
+impl SeedableRng<[u32; 4]> for Xorshift128 {
+    fn reseed(&mut self, seed: [u32; 4]) {
+        *self = Xorshift128::from_seed(seed);
+    }
+
+    fn from_seed(seed: [u32; 4]) -> Self {
+        if seed == [0, 0, 0, 0] {
+            panic!("Xorshift128::from_seed called with an all zero seed");
+        }
+        Xorshift128 {
+            x: seed[0],
+            y: seed[1],
+            z: seed[2],
+            w: seed[3],
+        }
+    }
+}
+
+impl Checkpoint for Xorshift128 {
+    fn save(&self, writer: &mut Writer) {
+        writer.write("xorshift128", vec![self.x.to_string(), self.y.to_string(),
+                                         self.z.to_string(), self.w.to_string()]);
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("xorshift128", Some(4)));
+        let mut state = [0u32; 4];
+        for (s, value) in state.iter_mut().zip(&values) {
+            *s = try!(parse(value));
+        }
+        self.reseed(state);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng, XorShiftRng};
+
+    use random::Xorshift128;
+
+    #[test]
+    fn xorshift128_matches_rand() {
+        let mut ours = Xorshift128::from_seed([1, 2, 3, 4]);
+        let mut theirs = XorShiftRng::from_seed([1, 2, 3, 4]);
+        for _ in 0..100 {
+            assert!(ours.next_u32() == theirs.next_u32());
+        }
+    }
+}
@@ -1,8 +1,11 @@
+use rand::Rng;
+
 use chromosome::Chromosome;
 use generation::Generation;
 use reinsertion::Reinsertion;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Elitist;
 
 impl Elitist {
@@ -12,7 +15,8 @@ impl Elitist {
 }
 
 impl<T> Reinsertion<T> for Elitist where T: Clone {
-    fn reinsert(&self, parents: &Generation<T>, mut offspring: Vec<Chromosome<T>>) -> Generation<T> {
+    fn reinsert<R>(&self, parents: &Generation<T>, mut offspring: Vec<Chromosome<T>>, _: &mut R)
+        -> Generation<T> where R: Rng {
         match parents.size().checked_sub(offspring.len()) {
             Some(diff) if diff > 0 => offspring.extend(parents.top(diff).iter().cloned()),
             _ => {},
@@ -1,16 +1,21 @@
 use std::marker::PhantomData;
 
+use rand::Rng;
+
 use chromosome::Chromosome;
+use evaluation::Evaluate;
 use generation::Generation;
 use reinsertion::Reinsertion;
 
+// Keeps the best offspring when there are more of them than parents. The offspring are evaluated with the
+// given fitness function, which should be the one used by the algorithm, so they aren't evaluated twice.
 #[derive(Copy, Clone)]
-pub struct FitnessBased<'a, T, F> where T: Clone, F: 'a + Fn(&[T]) -> f64 {
+pub struct FitnessBased<'a, T, F> where T: Clone, F: 'a + Evaluate<T> {
     fitness: &'a F,
     _gene: PhantomData<T>,
 }
 
-impl<'a, T, F> FitnessBased<'a, T, F> where T: Clone, F: 'a + Fn(&[T]) -> f64 {
+impl<'a, T, F> FitnessBased<'a, T, F> where T: Clone, F: 'a + Evaluate<T> {
     pub fn new(fitness: &'a F) -> Self {
         FitnessBased {
             fitness: fitness,
@@ -19,19 +24,56 @@ impl<'a, T, F> FitnessBased<'a, T, F> where T: Clone, F: 'a + Fn(&[T]) -> f64 {
     }
 }
 
-impl<'a, T, F> Reinsertion<T> for FitnessBased<'a, T, F> where T: Clone, F: 'a + Fn(&[T]) -> f64 {
-    fn reinsert(&self, parents: &Generation<T>, mut offspring: Vec<Chromosome<T>>) -> Generation<T> {
+impl<'a, T, F> Reinsertion<T> for FitnessBased<'a, T, F> where T: Clone, F: 'a + Evaluate<T> {
+    fn reinsert<R>(&self, parents: &Generation<T>, mut offspring: Vec<Chromosome<T>>, _: &mut R)
+        -> Generation<T> where R: Rng {
         let length = offspring.len();
+        let mut evaluations = 0;
         match length.checked_sub(parents.size()) {
             Some(diff) if diff > 0 => {
-                for c in offspring.iter_mut() {
-                    c.fitness = (*self.fitness)(c);
-                }
-                (*offspring).as_mut().sort_by(|a, b| Ord::cmp(a, b).reverse());
+                evaluations = self.fitness.evaluate(&mut offspring);
+                let objective = parents.objective();
+                (*offspring).as_mut().sort_by(|a, b| objective.compare(a.fitness, b.fitness).reverse());
                 offspring.truncate(length - diff);
             },
             _ => {},
         }
-        Generation::from(offspring)
+        let mut generation = Generation::from(offspring);
+        generation.add_evaluations(evaluations);
+        generation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use rand::SeedableRng;
+
+    use problem::{Problem, Permutation};
+    use random::Xorshift128;
+    use reinsertion::FitnessBased;
+    use selection::Tournament;
+    use termination::EvaluationBudget;
+    use tracking::BestSolution;
+
+    #[test]
+    fn fitness_based_counts_evaluations() {
+        let calls = AtomicUsize::new(0);
+        let fitness = |genes: &[usize]| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            genes[0] as f64
+        };
+        let mut rng = Xorshift128::from_seed([1, 2, 3, 4]);
+        let population = Permutation::from(0..10usize).generate_population(20, &mut rng);
+
+        // Selecting twice as many parents as needed leaves more offspring than the reinsertion keeps.
+        let mut algorithm = test_algorithm!(&fitness, Tournament::new(2.0, 3), FitnessBased::new(&fitness),
+                                            BestSolution::new());
+        algorithm.evolve(population, EvaluationBudget::new(200));
+
+        assert!(algorithm.generation().size() == 20);
+        let calls = calls.load(Ordering::SeqCst);
+        assert!(algorithm.generation().evaluations() == calls && calls >= 200);
     }
 }
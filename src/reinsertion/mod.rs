@@ -1,16 +1,42 @@
 mod elitist;
 mod fitness_based;
+mod nsga2;
 mod pure_reinsert;
 mod uniform;
 
+use rand::Rng;
+
 use chromosome::Chromosome;
 use generation::Generation;
 
 pub use self::elitist::Elitist;
 pub use self::fitness_based::FitnessBased;
+pub use self::nsga2::Nsga2;
 pub use self::pure_reinsert::Pure;
 pub use self::uniform::UniformReinsertion;
 
 pub trait Reinsertion<T> {
-    fn reinsert(&self, parents: &Generation<T>, offspring: Vec<Chromosome<T>>) -> Generation<T>;
+    fn reinsert<R>(&self, parents: &Generation<T>, offspring: Vec<Chromosome<T>>, rng: &mut R) -> Generation<T>
+        where R: Rng;
+}
+
+// An object safe version of `Reinsertion`, implemented for every reinsertion operator. Boxed operators
+// implement `Reinsertion` again.
+pub trait DynReinsertion<T> {
+    fn dyn_reinsert(&self, parents: &Generation<T>, offspring: Vec<Chromosome<T>>, rng: &mut Rng)
+        -> Generation<T>;
+}
+
+impl<T, RE> DynReinsertion<T> for RE where RE: Reinsertion<T> {
+    fn dyn_reinsert(&self, parents: &Generation<T>, offspring: Vec<Chromosome<T>>, mut rng: &mut Rng)
+        -> Generation<T> {
+        self.reinsert(parents, offspring, &mut rng)
+    }
+}
+
+impl<'a, T> Reinsertion<T> for Box<DynReinsertion<T> + 'a> {
+    fn reinsert<R>(&self, parents: &Generation<T>, offspring: Vec<Chromosome<T>>, rng: &mut R) -> Generation<T>
+        where R: Rng {
+        (**self).dyn_reinsert(parents, offspring, rng)
+    }
 }
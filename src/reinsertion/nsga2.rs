@@ -0,0 +1,68 @@
+use rand::Rng;
+
+use chromosome::Chromosome;
+use evaluation::Evaluate;
+use generation::Generation;
+use objective::Objective;
+use pareto::crowded_compare;
+use reinsertion::Reinsertion;
+
+// The elitist (μ + λ) replacement of NSGA-II. Parents and offspring are ranked together, and the next
+// generation is filled front by front, using the crowding distance to decide which chromosomes of the last
+// front that fits only partially survive. The evaluator has to be the same `pareto::Pareto` that's used by
+// the algorithm.
+pub struct Nsga2<'a, F> where F: 'a {
+    fitness: &'a F,
+}
+
+impl<'a, F> Nsga2<'a, F> {
+    pub fn new(fitness: &'a F) -> Self {
+        Nsga2 { fitness: fitness }
+    }
+}
+
+impl<'a, T, F> Reinsertion<T> for Nsga2<'a, F> where T: Clone, F: 'a + Evaluate<T> {
+    fn reinsert<R>(&self, parents: &Generation<T>, offspring: Vec<Chromosome<T>>, _: &mut R)
+        -> Generation<T> where R: Rng {
+        // The ranked fitness is always maximized, see `pareto::Pareto`.
+        if parents.objective() != Objective::Maximize {
+            panic!("NSGA-II reinsertion requires the objective to be left at maximize");
+        }
+        let mut union = parents.iter().cloned().collect::<Vec<_>>();
+        union.extend(offspring);
+
+        let evaluations = self.fitness.evaluate(&mut union);
+        union.sort_by(|a, b| crowded_compare(a, b).reverse());
+        union.truncate(parents.size());
+
+        let mut generation = Generation::from(union);
+        generation.add_evaluations(evaluations);
+        generation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use chromosome::Chromosome;
+    use generation::Generation;
+    use objective::Objective;
+    use pareto::Pareto;
+    use random::Xorshift128;
+    use reinsertion::{Nsga2, Reinsertion};
+
+    #[test]
+    fn nsga2_keeps_the_first_front() {
+        let fitness = |genes: &[i32]| vec![genes[0] as f64, genes[1] as f64];
+        let pareto = Pareto::new(&fitness, vec![Objective::Minimize, Objective::Minimize]);
+        let mut rng = Xorshift128::from_seed([1, 2, 3, 4]);
+
+        let parents = Generation::new(vec![vec![3, 3], vec![4, 4]], &pareto, Objective::Maximize);
+        let offspring = vec![Chromosome::from(vec![0, 5]), Chromosome::from(vec![5, 0])];
+        let generation = Nsga2::new(&pareto).reinsert(&parents, offspring, &mut rng);
+
+        assert!(generation.size() == 2);
+        assert!(generation.iter().all(|c| c.pareto.as_ref().unwrap().front == 0 && c.genes() != &[4, 4]));
+    }
+}
@@ -1,8 +1,11 @@
+use rand::Rng;
+
 use chromosome::Chromosome;
 use generation::Generation;
 use reinsertion::Reinsertion;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Pure;
 
 impl Pure {
@@ -12,7 +15,8 @@ impl Pure {
 }
 
 impl<T> Reinsertion<T> for Pure where T: Clone {
-    fn reinsert(&self, _: &Generation<T>, offspring: Vec<Chromosome<T>>) -> Generation<T> {
+    fn reinsert<R>(&self, _: &Generation<T>, offspring: Vec<Chromosome<T>>, _: &mut R)
+        -> Generation<T> where R: Rng {
         Generation::from(offspring)
     }
 }
@@ -1,4 +1,4 @@
-use rand;
+use rand::Rng;
 
 use chromosome::Chromosome;
 use generation::Generation;
@@ -6,6 +6,7 @@ use reinsertion::Reinsertion;
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UniformReinsertion;
 
 impl UniformReinsertion {
@@ -15,10 +16,10 @@ impl UniformReinsertion {
 }
 
 impl<T> Reinsertion<T> for UniformReinsertion where T: Clone {
-    fn reinsert(&self, parents: &Generation<T>, mut offspring: Vec<Chromosome<T>>) -> Generation<T> {
+    fn reinsert<R>(&self, parents: &Generation<T>, mut offspring: Vec<Chromosome<T>>, rng: &mut R)
+        -> Generation<T> where R: Rng {
         match parents.size().checked_sub(offspring.len()) {
             Some(diff) if diff > 0 => {
-                let mut rng = rand::thread_rng();
                 let mut random = Vec::with_capacity(diff);
                 for _ in 0..diff {
                     random.push(rng.choose1(parents).clone());
@@ -0,0 +1,36 @@
+use diversity::Measure;
+use schedule::{Progress, Schedule};
+
+// Raises the rate when the population loses its diversity (see `diversity::Measure`). As long as the
+// diversity is at least `threshold` the rate is `low`, below that it rises linearly up to `high` for a
+// population without any diversity.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DiversityDriven {
+    measure: Measure,
+    threshold: f64,
+    low: f64,
+    high: f64,
+}
+
+impl DiversityDriven {
+    pub fn new(measure: Measure, threshold: f64, low: f64, high: f64) -> Self {
+        if threshold <= 0.0 {
+            panic!("The diversity threshold has to be positive");
+        }
+        DiversityDriven {
+            measure: measure,
+            threshold: threshold,
+            low: low,
+            high: high,
+        }
+    }
+}
+
+impl<T> Schedule<T> for DiversityDriven where T: PartialEq {
+    fn rate(&mut self, _: f64, progress: &Progress<T>) -> f64 {
+        let diversity = self.measure.of(progress.generation);
+        let shortfall = (1.0 - diversity / self.threshold).max(0.0);
+        self.low + (self.high - self.low) * shortfall
+    }
+}
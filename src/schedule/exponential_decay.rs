@@ -0,0 +1,30 @@
+use schedule::{Progress, Schedule};
+
+// Moves the rate from `start` towards `end`, shrinking the remaining distance by `factor` (between 0 and 1)
+// every generation.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExponentialDecay {
+    start: f64,
+    end: f64,
+    factor: f64,
+}
+
+impl ExponentialDecay {
+    pub fn new(start: f64, end: f64, factor: f64) -> Self {
+        if factor < 0.0 || factor > 1.0 {
+            panic!("The decay factor has to be between 0 and 1");
+        }
+        ExponentialDecay {
+            start: start,
+            end: end,
+            factor: factor,
+        }
+    }
+}
+
+impl<T> Schedule<T> for ExponentialDecay {
+    fn rate(&mut self, _: f64, progress: &Progress<T>) -> f64 {
+        self.end + (self.start - self.end) * self.factor.powf(progress.index as f64)
+    }
+}
@@ -0,0 +1,31 @@
+use schedule::{Progress, Schedule};
+
+// Moves the rate from `start` to `end` in equal steps over the given number of generations, and keeps it at
+// `end` afterwards.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearDecay {
+    start: f64,
+    end: f64,
+    generations: usize,
+}
+
+impl LinearDecay {
+    pub fn new(start: f64, end: f64, generations: usize) -> Self {
+        if generations == 0 {
+            panic!("A linear decay needs at least one generation");
+        }
+        LinearDecay {
+            start: start,
+            end: end,
+            generations: generations,
+        }
+    }
+}
+
+impl<T> Schedule<T> for LinearDecay {
+    fn rate(&mut self, _: f64, progress: &Progress<T>) -> f64 {
+        let fraction = progress.index.min(self.generations) as f64 / self.generations as f64;
+        self.start + (self.end - self.start) * fraction
+    }
+}
@@ -0,0 +1,100 @@
+mod diversity_driven;
+mod exponential_decay;
+mod linear_decay;
+mod one_fifth;
+
+use generation::Generation;
+
+pub use self::diversity_driven::DiversityDriven;
+pub use self::exponential_decay::ExponentialDecay;
+pub use self::linear_decay::LinearDecay;
+pub use self::one_fifth::OneFifth;
+
+// What a schedule can base the next rate on. `index` is the index of the generation that was just produced
+// (the initial population being generation 0), and `successes` counts the new offspring of that generation
+// that are better than all of their parents.
+pub struct Progress<'a, T> where T: 'a {
+    pub index: usize,
+    pub generation: &'a Generation<T>,
+    pub offspring: usize,
+    pub successes: usize,
+}
+
+impl<'a, T> Progress<'a, T> {
+    // The fraction of new offspring that improved on their parents, or `None` if there weren't any.
+    pub fn success_ratio(&self) -> Option<f64> {
+        if self.offspring == 0 {
+            None
+        } else {
+            Some(self.successes as f64 / self.offspring as f64)
+        }
+    }
+}
+
+// Adapts the mutation or crossover rate of an algorithm (see `Algorithm::mutation_schedule` and
+// `Algorithm::crossover_schedule`). It's called after every generation with the current rate, and returns
+// the rate for the next one. Every closure with the same signature is a schedule as well.
+pub trait Schedule<T> {
+    fn rate(&mut self, rate: f64, progress: &Progress<T>) -> f64;
+}
+
+impl<T, F> Schedule<T> for F where F: FnMut(f64, &Progress<T>) -> f64 {
+    fn rate(&mut self, rate: f64, progress: &Progress<T>) -> f64 {
+        self(rate, progress)
+    }
+}
+
+impl<'a, T> Schedule<T> for Box<Schedule<T> + Send + 'a> {
+    fn rate(&mut self, rate: f64, progress: &Progress<T>) -> f64 {
+        (**self).rate(rate, progress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use diversity::Measure;
+    use generation::Generation;
+    use objective::Objective;
+    use problem::{Problem, Permutation};
+    use random::Xorshift128;
+    use schedule::*;
+    use termination::Iterations;
+    use tracking::BestSolution;
+
+    #[test]
+    fn schedules() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let generation = Generation::new(vec![vec![1], vec![1]], &fitness, Objective::Maximize);
+        let progress = |index, successes| {
+            Progress { index: index, generation: &generation, offspring: 10, successes: successes }
+        };
+
+        let mut linear = LinearDecay::new(0.5, 0.1, 4);
+        assert_approx_eq!(linear.rate(0.5, &progress(2, 0)), 0.3, 1e-9);
+        assert_approx_eq!(linear.rate(0.3, &progress(8, 0)), 0.1, 1e-9);
+        let mut exponential = ExponentialDecay::new(0.5, 0.1, 0.5);
+        assert_approx_eq!(exponential.rate(0.5, &progress(2, 0)), 0.2, 1e-9);
+        let mut one_fifth = OneFifth::new(0.5, 0.01, 0.4);
+        assert_approx_eq!(one_fifth.rate(0.1, &progress(1, 5)), 0.2, 1e-9);
+        assert_approx_eq!(one_fifth.rate(0.1, &progress(1, 1)), 0.05, 1e-9);
+        assert_approx_eq!(one_fifth.rate(0.3, &progress(1, 5)), 0.4, 1e-9);
+        let mut diversity = DiversityDriven::new(Measure::UniqueRatio, 1.0, 0.1, 0.3);
+        assert_approx_eq!(diversity.rate(0.1, &progress(1, 0)), 0.2, 1e-9);
+    }
+
+    #[test]
+    fn scheduled_rates_change_every_generation() {
+        let fitness = |genes: &[usize]| genes[0] as f64;
+        let mut rng = Xorshift128::from_seed([1, 2, 3, 4]);
+        let population = Permutation::from(0..10usize).generate_population(20, &mut rng);
+        let mut algorithm = test_algorithm!(&fitness, BestSolution::new())
+            .mutation_schedule(LinearDecay::new(0.5, 0.1, 4))
+            .crossover_schedule(|rate: f64, _: &Progress<usize>| rate / 2.0);
+
+        algorithm.evolve(population, Iterations::new(2));
+        assert_approx_eq!(algorithm.mutation_rate(), 0.3, 1e-9);
+        assert_approx_eq!(algorithm.crossover_rate(), 0.2, 1e-9);
+    }
+}
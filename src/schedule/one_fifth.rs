@@ -0,0 +1,37 @@
+use schedule::{Progress, Schedule};
+
+// Rechenberg's 1/5 success rule. If more than a fifth of the new offspring improved on their parents, the
+// rate is divided by `factor` (between 0 and 1, usually around 0.82) to explore more, if fewer did it's
+// multiplied by it. The rate always stays between `min` and `max`, and generations without new offspring
+// leave it unchanged.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OneFifth {
+    factor: f64,
+    min: f64,
+    max: f64,
+}
+
+impl OneFifth {
+    pub fn new(factor: f64, min: f64, max: f64) -> Self {
+        if factor <= 0.0 || factor > 1.0 {
+            panic!("The adjustment factor has to be between 0 and 1");
+        }
+        OneFifth {
+            factor: factor,
+            min: min,
+            max: max,
+        }
+    }
+}
+
+impl<T> Schedule<T> for OneFifth {
+    fn rate(&mut self, rate: f64, progress: &Progress<T>) -> f64 {
+        let rate = match progress.success_ratio() {
+            Some(ratio) if ratio > 0.2 => rate / self.factor,
+            Some(ratio) if ratio < 0.2 => rate * self.factor,
+            _ => rate,
+        };
+        rate.max(self.min).min(self.max)
+    }
+}
@@ -0,0 +1,100 @@
+use std::cell::Cell;
+use std::io;
+
+use rand::Rng;
+
+use checkpoint::{Checkpoint, Reader, Writer, parse};
+use chromosome::Chromosome;
+use crossover::Crossover;
+use generation::Generation;
+use selection::{Selection, selection_size, spin_wheel};
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Boltzmann {
+    retain_ratio: f64,
+    temperature: Cell<f64>,
+    cooling_rate: f64,
+    min_temperature: f64,
+}
+
+impl Boltzmann {
+    pub fn new(retain_ratio: f64, temperature: f64) -> Self {
+        Boltzmann::annealed(retain_ratio, temperature, 1.0, temperature)
+    }
+
+    // The temperature is multiplied by the cooling rate after every selection, but it never drops below
+    // the given minimum.
+    pub fn annealed(retain_ratio: f64, temperature: f64, cooling_rate: f64, min_temperature: f64) -> Self {
+        if temperature <= 0.0 || min_temperature <= 0.0 {
+            panic!("Boltzmann selection requires a positive temperature");
+        }
+        Boltzmann {
+            retain_ratio: retain_ratio,
+            temperature: Cell::new(temperature),
+            cooling_rate: cooling_rate,
+            min_temperature: min_temperature,
+        }
+    }
+
+    pub fn temperature(&self) -> f64 {
+        self.temperature.get()
+    }
+}
+
+impl<T> Selection<T> for Boltzmann where T: Clone {
+    fn retain_ratio(&self) -> f64 {
+        self.retain_ratio
+    }
+
+    fn select<C, R>(&self, generation: &Generation<T>, crossover: &C, rng: &mut R) -> Vec<Chromosome<T>>
+        where C: Crossover<T>, R: Rng {
+
+        let selection_size = selection_size(self.retain_ratio, generation, crossover);
+        let temperature = self.temperature.get();
+
+        // Subtracting the best score keeps every exponent non-positive, so the weights can't overflow.
+        let objective = generation.objective();
+        let best = objective.score(generation.best().fitness);
+        let weights = generation.iter()
+            .map(|c| ((objective.score(c.fitness) - best) / temperature).exp())
+            .collect::<Vec<_>>();
+        let total = weights.iter().fold(0.0, |sum, w| sum + w);
+
+        let mut selected = Vec::with_capacity(selection_size);
+        for _ in 0..selection_size {
+            let index = spin_wheel(&weights, total, rng);
+            selected.push(generation[index].clone());
+        }
+
+        self.temperature.set((temperature * self.cooling_rate).max(self.min_temperature));
+        selected
+    }
+}
+
+// Only the current temperature changes during a run.
+impl Checkpoint for Boltzmann {
+    fn save(&self, writer: &mut Writer) {
+        writer.write("temperature", vec![self.temperature.get().to_string()]);
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("temperature", Some(1)));
+        self.temperature.set(try!(parse(&values[0])));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boltzmann_annealing() {
+        let boltzmann = Boltzmann::annealed(1.0, 10.0, 0.5, 1.0);
+        for _ in 0..5 {
+            test_select!(boltzmann, Maximize, |genes: &[i32]| genes[0] as f64, 0, 1, 2, 3);
+        }
+        assert_approx_eq!(boltzmann.temperature(), 1.0, 1e-9);
+    }
+}
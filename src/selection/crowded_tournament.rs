@@ -0,0 +1,52 @@
+use std::cmp::Ordering;
+
+use rand::Rng;
+
+use chromosome::Chromosome;
+use crossover::Crossover;
+use generation::Generation;
+use pareto::crowded_compare;
+use selection::{Selection, selection_size};
+use utility::RngExt;
+
+// The tournament selection of NSGA-II, which prefers chromosomes from a better Pareto front and breaks ties
+// by the crowding distance. Only meaningful for generations evaluated by `pareto::Pareto`.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CrowdedTournament {
+    retain_ratio: f64,
+    size: usize,
+}
+
+impl CrowdedTournament {
+    pub fn new(retain_ratio: f64, tournament_size: usize) -> Self {
+        CrowdedTournament {
+            retain_ratio: retain_ratio,
+            size: tournament_size,
+        }
+    }
+}
+
+impl<T> Selection<T> for CrowdedTournament where T: Clone {
+    fn retain_ratio(&self) -> f64 {
+        self.retain_ratio
+    }
+
+    fn select<C, R>(&self, generation: &Generation<T>, crossover: &C, rng: &mut R) -> Vec<Chromosome<T>>
+        where C: Crossover<T>, R: Rng {
+
+        let selection_size = selection_size(self.retain_ratio, generation, crossover);
+        let mut selected = Vec::with_capacity(selection_size);
+        for _ in 0..selection_size {
+            let mut winner = rng.choose1(&generation[..]);
+            for _ in 0..self.size {
+                let current = rng.choose1(&generation[..]);
+                if crowded_compare(current, winner) == Ordering::Greater {
+                    winner = current;
+                }
+            }
+            selected.push(winner.clone());
+        }
+        selected
+    }
+}
@@ -0,0 +1,16 @@
+// Runs a selection on a generation of single gene chromosomes, using a seeded random number generator.
+macro_rules! test_select {
+    ($selection: expr, $objective: ident, $fitness: expr, $($gene: expr),+) => {{
+        use rand::SeedableRng;
+        use crossover::OnePoint;
+        use generation::Generation;
+        use objective::Objective;
+        use random::Xorshift128;
+        use selection::Selection;
+
+        let fitness = $fitness;
+        let population: Vec<Vec<i32>> = vec![$(vec![$gene]),+];
+        let generation = Generation::new(population, &fitness, Objective::$objective);
+        $selection.select(&generation, &OnePoint::new(), &mut Xorshift128::from_seed([1, 2, 3, 4]))
+    }};
+}
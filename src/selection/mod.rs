@@ -1,15 +1,66 @@
+#[cfg(test)]
+#[macro_use]
+mod macros;
+
+mod boltzmann;
+mod crowded_tournament;
+mod rank;
+mod roulette;
+mod stochastic_universal;
 mod tournament;
+mod truncation;
+
+use rand::Rng;
 
-use crossover::Crossover;
+use chromosome::Chromosome;
+use crossover::{Crossover, DynCrossover};
 use generation::Generation;
 
+pub use self::boltzmann::Boltzmann;
+pub use self::crowded_tournament::CrowdedTournament;
+pub use self::rank::{Pressure, Rank};
+pub use self::roulette::Roulette;
+pub use self::stochastic_universal::StochasticUniversal;
 pub use self::tournament::Tournament;
+pub use self::truncation::Truncation;
+
+stateless_checkpoint!(CrowdedTournament, Rank, Roulette, StochasticUniversal, Tournament, Truncation);
 
 pub trait Selection<T> where T: Clone {
     fn retain_ratio(&self) -> f64;
 
-    fn select<C>(&self, generation: &Generation<T>, crossover: &C) -> Vec<Vec<T>>
-        where C: Crossover<T>;
+    fn select<C, R>(&self, generation: &Generation<T>, crossover: &C, rng: &mut R) -> Vec<Chromosome<T>>
+        where C: Crossover<T>, R: Rng;
+}
+
+// An object safe version of `Selection`, implemented for every selection operator. Boxed operators implement
+// `Selection` again.
+pub trait DynSelection<T> where T: Clone {
+    fn dyn_retain_ratio(&self) -> f64;
+    fn dyn_select(&self, generation: &Generation<T>, crossover: &DynCrossover<T>, rng: &mut Rng)
+        -> Vec<Chromosome<T>>;
+}
+
+impl<T, S> DynSelection<T> for S where T: Clone, S: Selection<T> {
+    fn dyn_retain_ratio(&self) -> f64 {
+        self.retain_ratio()
+    }
+
+    fn dyn_select(&self, generation: &Generation<T>, crossover: &DynCrossover<T>, mut rng: &mut Rng)
+        -> Vec<Chromosome<T>> {
+        self.select(generation, &crossover, &mut rng)
+    }
+}
+
+impl<'a, T> Selection<T> for Box<DynSelection<T> + 'a> where T: Clone {
+    fn retain_ratio(&self) -> f64 {
+        (**self).dyn_retain_ratio()
+    }
+
+    fn select<C, R>(&self, generation: &Generation<T>, crossover: &C, rng: &mut R) -> Vec<Chromosome<T>>
+        where C: Crossover<T>, R: Rng {
+        (**self).dyn_select(generation, crossover, rng)
+    }
 }
 
 #[inline]
@@ -23,3 +74,30 @@ pub fn selection_size<T, C>(retain_ratio: f64, generation: &Generation<T>, cross
     let num_selected = (size * retain_ratio * parents_to_children) as usize;
     (num_selected / parents) * parents
 }
+
+// Fitness proportionate selections work with the scores of the chromosomes (see `Objective::score`), and
+// they can't have a negative share, so whenever the worst chromosome has a non-positive score every value is
+// shifted up until the worst one sits at zero.
+fn fitness_offset<T>(generation: &Generation<T>) -> f64 {
+    let worst = generation.objective().score(generation[generation.size() - 1].fitness);
+    if worst <= 0.0 {
+        -worst
+    } else {
+        0.0
+    }
+}
+
+// Picks an index with a probability proportional to its weight, `total` being the sum of all weights.
+fn spin_wheel<R>(weights: &[f64], total: f64, rng: &mut R) -> usize where R: Rng {
+    let target = rng.next_f64() * total;
+    let mut sum = 0.0;
+    for (i, weight) in weights.iter().enumerate() {
+        sum += *weight;
+        if sum > target {
+            return i;
+        }
+    }
+
+    // Rounding errors can leave the target just above the accumulated sum.
+    weights.len() - 1
+}
@@ -0,0 +1,120 @@
+use rand::Rng;
+
+use chromosome::Chromosome;
+use crossover::Crossover;
+use generation::Generation;
+use selection::{Selection, selection_size, spin_wheel};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Pressure {
+    /// The selection pressure of linear ranking, in the range of `[1.0, 2.0]`. The best chromosome is
+    /// expected to be selected `pressure` times, the worst one `2.0 - pressure` times.
+    Linear(f64),
+    /// The base of exponential ranking, in the range of `(0.0, 1.0)`. The chromosome at rank `k` (where 0 is
+    /// the best) has a weight of `base^k`, so lower values mean a higher pressure.
+    Exponential(f64),
+}
+
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rank {
+    retain_ratio: f64,
+    pressure: Pressure,
+}
+
+impl Rank {
+    pub fn new(retain_ratio: f64, pressure: Pressure) -> Self {
+        match pressure {
+            Pressure::Linear(p) if p < 1.0 || p > 2.0 => {
+                panic!("Linear ranking pressure must be between 1.0 and 2.0");
+            },
+            Pressure::Exponential(b) if b <= 0.0 || b >= 1.0 => {
+                panic!("Exponential ranking base must be between 0.0 and 1.0 (exclusive)");
+            },
+            _ => {},
+        }
+        Rank {
+            retain_ratio: retain_ratio,
+            pressure: pressure,
+        }
+    }
+
+    pub fn linear(retain_ratio: f64, pressure: f64) -> Self {
+        Rank::new(retain_ratio, Pressure::Linear(pressure))
+    }
+
+    pub fn exponential(retain_ratio: f64, base: f64) -> Self {
+        Rank::new(retain_ratio, Pressure::Exponential(base))
+    }
+}
+
+impl<T> Selection<T> for Rank where T: Clone {
+    fn retain_ratio(&self) -> f64 {
+        self.retain_ratio
+    }
+
+    fn select<C, R>(&self, generation: &Generation<T>, crossover: &C, rng: &mut R) -> Vec<Chromosome<T>>
+        where C: Crossover<T>, R: Rng {
+
+        let selection_size = selection_size(self.retain_ratio, generation, crossover);
+        let weights = rank_weights(self.pressure, generation.size());
+        let total = weights.iter().fold(0.0, |sum, w| sum + w);
+
+        let mut selected = Vec::with_capacity(selection_size);
+        for _ in 0..selection_size {
+            let index = spin_wheel(&weights, total, rng);
+            selected.push(generation[index].clone());
+        }
+        selected
+    }
+}
+
+// Generations are kept sorted best-first, so the weight at index `k` belongs to the chromosome with rank `k`.
+fn rank_weights(pressure: Pressure, size: usize) -> Vec<f64> {
+    if size == 1 {
+        return vec![1.0];
+    }
+
+    let n = size as f64;
+    (0..size).map(|k| {
+        match pressure {
+            Pressure::Linear(p) => {
+                let i = (size - 1 - k) as f64;
+                (2.0 - p) / n + 2.0 * i * (p - 1.0) / (n * (n - 1.0))
+            },
+            Pressure::Exponential(b) => b.powi(k as i32),
+        }
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_linear_weights() {
+        let weights = rank_weights(Pressure::Linear(2.0), 5);
+        let total = weights.iter().fold(0.0, |sum, w| sum + w);
+
+        assert_approx_eq!(total, 1.0, 1e-9);
+        assert_approx_eq!(weights[0], 0.4, 1e-9);
+        assert_approx_eq!(weights[4], 0.0, 1e-9);
+    }
+
+    #[test]
+    fn rank_ignores_fitness_magnitude() {
+        let fitness = |genes: &[i32]| 10.0f64.powi(genes[0] * 100);
+        let selected = test_select!(Rank::linear(25.0, 1.0), Maximize, fitness, 0, 1, 2, 3);
+
+        // A pressure of 1.0 gives every rank the same weight, no matter how far apart the fitness values are.
+        let best = selected.iter().filter(|genes| genes[0] == 3).count();
+        assert!(best < selected.len() / 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rank_invalid_linear_pressure() {
+        Rank::linear(1.0, 2.5);
+    }
+}
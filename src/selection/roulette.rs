@@ -0,0 +1,76 @@
+use rand::Rng;
+
+use chromosome::Chromosome;
+use crossover::Crossover;
+use generation::Generation;
+use selection::{Selection, fitness_offset, selection_size, spin_wheel};
+use utility::RngExt;
+
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Roulette {
+    retain_ratio: f64,
+}
+
+impl Roulette {
+    pub fn new(retain_ratio: f64) -> Self {
+        Roulette { retain_ratio: retain_ratio }
+    }
+}
+
+impl<T> Selection<T> for Roulette where T: Clone {
+    fn retain_ratio(&self) -> f64 {
+        self.retain_ratio
+    }
+
+    fn select<C, R>(&self, generation: &Generation<T>, crossover: &C, rng: &mut R) -> Vec<Chromosome<T>>
+        where C: Crossover<T>, R: Rng {
+
+        let selection_size = selection_size(self.retain_ratio, generation, crossover);
+        let offset = fitness_offset(generation);
+        let objective = generation.objective();
+        let weights = generation.iter()
+            .map(|c| objective.score(c.fitness) + offset)
+            .collect::<Vec<_>>();
+        let total = weights.iter().fold(0.0, |sum, w| sum + w);
+
+        let mut selected = Vec::with_capacity(selection_size);
+        for _ in 0..selection_size {
+            // Every chromosome has the same (zero) weight, fall back to a uniform choice.
+            let index = if total > 0.0 { spin_wheel(&weights, total, rng) } else { rng.index(&weights) };
+            selected.push(generation[index].clone());
+        }
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roulette_negative_fitness() {
+        let fitness = |genes: &[i32]| genes[0] as f64 - 10.0;
+        let selected = test_select!(Roulette::new(1.0), Maximize, fitness, 0, 1, 2, 3);
+
+        assert!(selected.len() == 4);
+        assert!(selected.iter().all(|genes| genes[0] != 0));
+    }
+
+    #[test]
+    fn roulette_minimize() {
+        let fitness = |genes: &[i32]| genes[0] as f64 + 1.0;
+        let selected = test_select!(Roulette::new(1.0), Minimize, fitness, 0, 1, 2, 3);
+
+        // The scores are shifted so the largest fitness has a weight of zero.
+        assert!(selected.len() == 4);
+        assert!(selected.iter().all(|genes| genes[0] != 3));
+    }
+
+    #[test]
+    fn roulette_zero_fitness() {
+        let selected = test_select!(Roulette::new(1.0), Maximize, |_: &[i32]| 0.0, 0, 1, 2, 3);
+
+        assert!(selected.len() == 4);
+    }
+}
@@ -0,0 +1,85 @@
+use rand::Rng;
+
+use chromosome::Chromosome;
+use crossover::Crossover;
+use generation::Generation;
+use selection::{Selection, fitness_offset, selection_size};
+use utility::RngExt;
+
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StochasticUniversal {
+    retain_ratio: f64,
+}
+
+impl StochasticUniversal {
+    pub fn new(retain_ratio: f64) -> Self {
+        StochasticUniversal { retain_ratio: retain_ratio }
+    }
+}
+
+impl<T> Selection<T> for StochasticUniversal where T: Clone {
+    fn retain_ratio(&self) -> f64 {
+        self.retain_ratio
+    }
+
+    fn select<C, R>(&self, generation: &Generation<T>, crossover: &C, rng: &mut R) -> Vec<Chromosome<T>>
+        where C: Crossover<T>, R: Rng {
+
+        let selection_size = selection_size(self.retain_ratio, generation, crossover);
+        let offset = fitness_offset(generation);
+        let objective = generation.objective();
+        let total = objective.score(generation.total_fitness()) + offset * generation.size() as f64;
+
+        let mut selected = Vec::with_capacity(selection_size);
+        if selection_size == 0 {
+            return selected;
+        }
+
+        // Every chromosome has the same (zero) weight, fall back to a uniform choice.
+        if total <= 0.0 {
+            for _ in 0..selection_size {
+                selected.push(rng.choose1(&generation[..]).clone());
+            }
+            return selected;
+        }
+
+        let spacing = total / selection_size as f64;
+        let mut pointer = rng.next_f64() * spacing;
+        let mut index = 0;
+        let mut sum = objective.score(generation[0].fitness) + offset;
+
+        for _ in 0..selection_size {
+            while sum <= pointer && index < generation.size() - 1 {
+                index += 1;
+                sum += objective.score(generation[index].fitness) + offset;
+            }
+            selected.push(generation[index].clone());
+            pointer += spacing;
+        }
+
+        // The sweep returns the parents ordered by fitness, which would make neighbouring parents (the
+        // ones that get crossed with each other) copies of the same chromosome most of the time.
+        rng.shuffle(&mut selected);
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stochastic_universal_proportional() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let selected = test_select!(StochasticUniversal::new(2.0), Maximize, fitness, 1, 1, 2, 4);
+
+        // With 8 evenly spaced pointers over a total fitness of 8 every unit of fitness gets exactly
+        // one pointer.
+        let count = |gene| selected.iter().filter(|genes| genes[0] == gene).count();
+        assert!(selected.len() == 8);
+        assert!(count(1) == 2);
+        assert!(count(2) == 2);
+        assert!(count(4) == 4);
+    }
+}
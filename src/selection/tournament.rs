@@ -1,12 +1,14 @@
-use rand::{self, Rng};
+use rand::Rng;
 
 use chromosome::Chromosome;
 use crossover::Crossover;
 use generation::Generation;
+use objective::Objective;
 use selection::{Selection, selection_size};
 use utility::RngExt;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Tournament {
     retain_ratio: f64,
     size: usize,
@@ -26,31 +28,44 @@ impl<T> Selection<T> for Tournament where T: Clone {
         self.retain_ratio
     }
 
-    fn select<C>(&self, generation: &Generation<T>, crossover: &C) -> Vec<Vec<T>>
-        where C: Crossover<T> {
-
-        let mut rng = rand::thread_rng();
+    fn select<C, R>(&self, generation: &Generation<T>, crossover: &C, rng: &mut R) -> Vec<Chromosome<T>>
+        where C: Crossover<T>, R: Rng {
 
         let selection_size = selection_size(self.retain_ratio, generation, crossover);
         let mut selected = Vec::with_capacity(selection_size);
         for _ in 0..selection_size {
-            selected.push(hold_tournament(&generation, self.size, &mut rng));
+            selected.push(hold_tournament(&generation, generation.objective(), self.size, rng));
         }
         selected
     }
 }
 
-fn hold_tournament<T, R>(chromosomes: &[Chromosome<T>],
-                         tournament_size: usize, rng: &mut R) -> Vec<T>
+fn hold_tournament<T, R>(chromosomes: &[Chromosome<T>], objective: Objective,
+                         tournament_size: usize, rng: &mut R) -> Chromosome<T>
     where T: Clone, R: Rng {
 
     let mut max = rng.choose1(chromosomes);
     for _ in 0..tournament_size {
         let current = rng.choose1(chromosomes);
-        if current > max {
+        if objective.is_better(current.fitness, max.fitness) {
             max = current;
         }
     }
 
-    max.into_iter().cloned().collect()
+    max.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tournament_minimize() {
+        // With 100 rounds per tournament the smallest fitness is (practically) always in it.
+        let fitness = |genes: &[i32]| genes[0] as f64 + 10.0;
+        let selected = test_select!(Tournament::new(1.0, 100), Minimize, fitness, 3, 1, 0, 2);
+
+        assert!(selected.len() == 4);
+        assert!(selected.iter().all(|genes| genes[0] == 0));
+    }
 }
@@ -0,0 +1,63 @@
+use std::cmp;
+
+use rand::Rng;
+
+use chromosome::Chromosome;
+use crossover::Crossover;
+use generation::Generation;
+use selection::{Selection, selection_size};
+use utility::RngExt;
+
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Truncation {
+    retain_ratio: f64,
+    fraction: f64,
+}
+
+impl Truncation {
+    pub fn new(retain_ratio: f64, fraction: f64) -> Self {
+        if fraction <= 0.0 || fraction > 1.0 {
+            panic!("Truncation fraction must be between 0.0 (exclusive) and 1.0");
+        }
+        Truncation {
+            retain_ratio: retain_ratio,
+            fraction: fraction,
+        }
+    }
+}
+
+impl<T> Selection<T> for Truncation where T: Clone {
+    fn retain_ratio(&self) -> f64 {
+        self.retain_ratio
+    }
+
+    fn select<C, R>(&self, generation: &Generation<T>, crossover: &C, rng: &mut R) -> Vec<Chromosome<T>>
+        where C: Crossover<T>, R: Rng {
+
+        let selection_size = selection_size(self.retain_ratio, generation, crossover);
+        let size = generation.size();
+        let cutoff = cmp::min(cmp::max((size as f64 * self.fraction).ceil() as usize, 1), size);
+        let top = generation.top(cutoff);
+
+        let mut selected = Vec::with_capacity(selection_size);
+        for _ in 0..selection_size {
+            selected.push(rng.choose1(top).clone());
+        }
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncation_top_half() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let selected = test_select!(Truncation::new(10.0, 0.5), Maximize, fitness, 0, 1, 2, 3);
+
+        assert!(selected.len() == 40);
+        assert!(selected.iter().all(|genes| genes[0] >= 2));
+    }
+}
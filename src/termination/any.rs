@@ -0,0 +1,44 @@
+use std::io;
+
+use checkpoint::{Checkpoint, Reader, Writer};
+use generation::Generation;
+use termination::Termination;
+
+// Reached as soon as either condition is reached. Both conditions are checked in every generation, so
+// stateful conditions (such as `Iterations`) keep counting even when the other one already decided the
+// outcome.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Any<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Any<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Any {
+            first: first,
+            second: second,
+        }
+    }
+}
+
+impl<T, A, B> Termination<T> for Any<A, B> where A: Termination<T>, B: Termination<T> {
+    fn reached(&mut self, generation: &Generation<T>) -> bool {
+        let first = self.first.reached(generation);
+        let second = self.second.reached(generation);
+        first || second
+    }
+}
+
+impl<A, B> Checkpoint for Any<A, B> where A: Checkpoint, B: Checkpoint {
+    fn save(&self, writer: &mut Writer) {
+        self.first.save(writer);
+        self.second.save(writer);
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        try!(self.first.restore(reader));
+        self.second.restore(reader)
+    }
+}
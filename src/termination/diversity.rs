@@ -0,0 +1,42 @@
+use std::io;
+
+use checkpoint::{Checkpoint, Reader, Writer};
+use diversity::Measure;
+use generation::Generation;
+use termination::Termination;
+
+// Reached when the diversity of the generation (see `diversity::Measure`) drops to or below the threshold,
+// i.e. when the population has collapsed. Unlike `FitnessConvergence` this doesn't care whether the best
+// fitness still changes.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Diversity {
+    measure: Measure,
+    threshold: f64,
+}
+
+impl Diversity {
+    pub fn new(measure: Measure, threshold: f64) -> Self {
+        Diversity {
+            measure: measure,
+            threshold: threshold,
+        }
+    }
+}
+
+impl<T> Termination<T> for Diversity where T: PartialEq {
+    fn reached(&mut self, generation: &Generation<T>) -> bool {
+        self.measure.of(generation) <= self.threshold
+    }
+}
+
+impl Checkpoint for Diversity {
+    fn save(&self, writer: &mut Writer) {
+        writer.write("diversity", Vec::new());
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        try!(reader.read("diversity", Some(0)));
+        Ok(())
+    }
+}
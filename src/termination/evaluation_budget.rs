@@ -0,0 +1,39 @@
+use std::io;
+
+use checkpoint::{Checkpoint, Reader, Writer};
+use generation::Generation;
+use termination::Termination;
+
+// Reached once the fitness function was called at least `evaluations` times, including the evaluation of
+// the initial population. Chromosomes that don't have to be evaluated again (unchanged parents, or hits of
+// a `Cached` fitness function) don't count, so runs with different population sizes or operators can be
+// compared at the same cost.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EvaluationBudget {
+    evaluations: usize,
+}
+
+impl EvaluationBudget {
+    pub fn new(evaluations: usize) -> Self {
+        EvaluationBudget { evaluations: evaluations }
+    }
+}
+
+impl<T> Termination<T> for EvaluationBudget {
+    fn reached(&mut self, generation: &Generation<T>) -> bool {
+        generation.evaluations() >= self.evaluations
+    }
+}
+
+// The number of evaluations is part of the generation, so there's nothing to save here.
+impl Checkpoint for EvaluationBudget {
+    fn save(&self, writer: &mut Writer) {
+        writer.write("evaluation-budget", Vec::new());
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        try!(reader.read("evaluation-budget", Some(0)));
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
+use std::io;
+
 use approx::{self, Abs};
 
+use checkpoint::{Checkpoint, Reader, Writer, parse};
 use generation::Generation;
 use termination::Termination;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FitnessConvergence {
     previous: Option<f64>,
     target_generations: usize,
@@ -22,8 +26,8 @@ impl FitnessConvergence {
     }
 }
 
-impl Termination for FitnessConvergence {
-    fn reached<T>(&mut self, generation: &Generation<T>) -> bool {
+impl<T> Termination<T> for FitnessConvergence {
+    fn reached(&mut self, generation: &Generation<T>) -> bool {
         let best = generation.best().fitness;
         let previous = match self.previous {
             None => {
@@ -42,3 +46,23 @@ impl Termination for FitnessConvergence {
         }
     }
 }
+
+impl Checkpoint for FitnessConvergence {
+    fn save(&self, writer: &mut Writer) {
+        let previous = match self.previous {
+            Some(previous) => previous.to_string(),
+            None => "none".to_owned(),
+        };
+        writer.write("fitness-convergence", vec![previous, self.elapsed_generations.to_string()]);
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("fitness-convergence", Some(2)));
+        self.previous = match &values[0][..] {
+            "none" => None,
+            previous => Some(try!(parse(previous))),
+        };
+        self.elapsed_generations = try!(parse(&values[1]));
+        Ok(())
+    }
+}
@@ -1,7 +1,12 @@
+use std::cmp::Ordering;
+use std::io;
+
+use checkpoint::{Checkpoint, Reader, Writer};
 use generation::Generation;
 use termination::Termination;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FitnessThreshold {
     threshold: f64,
 }
@@ -12,8 +17,37 @@ impl FitnessThreshold {
     }
 }
 
-impl Termination for FitnessThreshold {
-    fn reached<T>(&mut self, generation: &Generation<T>) -> bool {
-        generation.best().fitness >= self.threshold
+impl<T> Termination<T> for FitnessThreshold {
+    fn reached(&mut self, generation: &Generation<T>) -> bool {
+        generation.objective().compare(generation.best().fitness, self.threshold) != Ordering::Less
+    }
+}
+
+impl Checkpoint for FitnessThreshold {
+    fn save(&self, writer: &mut Writer) {
+        writer.write("fitness-threshold", Vec::new());
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        try!(reader.read("fitness-threshold", Some(0)));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use generation::Generation;
+    use objective::Objective;
+    use termination::{FitnessThreshold, Termination};
+
+    #[test]
+    fn fitness_threshold_minimize() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let mut threshold = FitnessThreshold::new(1.0);
+
+        let generation = Generation::new(vec![vec![4], vec![2]], &fitness, Objective::Minimize);
+        assert!(!threshold.reached(&generation));
+        let generation = Generation::new(vec![vec![4], vec![1]], &fitness, Objective::Minimize);
+        assert!(threshold.reached(&generation));
     }
 }
@@ -1,7 +1,11 @@
+use std::io;
+
+use checkpoint::{Checkpoint, Reader, Writer, parse};
 use generation::Generation;
 use termination::Termination;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Iterations {
     iterations: isize,
 }
@@ -12,9 +16,24 @@ impl Iterations {
     }
 }
 
-impl Termination for Iterations {
-    fn reached<T>(&mut self, _: &Generation<T>) -> bool {
-        self.iterations -= 1;
-        self.iterations == -1
+impl<T> Termination<T> for Iterations {
+    // Stays reached once the iterations ran out, so it can be combined with other conditions.
+    fn reached(&mut self, _: &Generation<T>) -> bool {
+        if self.iterations > -1 {
+            self.iterations -= 1;
+        }
+        self.iterations <= -1
+    }
+}
+
+impl Checkpoint for Iterations {
+    fn save(&self, writer: &mut Writer) {
+        writer.write("iterations", vec![self.iterations.to_string()]);
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("iterations", Some(1)));
+        self.iterations = try!(parse(&values[0]));
+        Ok(())
     }
 }
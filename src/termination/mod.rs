@@ -1,13 +1,114 @@
+mod all;
+mod any;
+mod diversity;
+mod evaluation_budget;
 mod fitness_convergence;
 mod fitness_threshold;
 mod iterations;
+mod not;
+mod time_limit;
 
 use generation::Generation;
 
+pub use self::all::All;
+pub use self::any::Any;
+pub use self::diversity::Diversity;
+pub use self::evaluation_budget::EvaluationBudget;
 pub use self::fitness_convergence::FitnessConvergence;
 pub use self::fitness_threshold::FitnessThreshold;
 pub use self::iterations::Iterations;
+pub use self::not::Not;
+pub use self::time_limit::TimeLimit;
 
-pub trait Termination {
-    fn reached<T>(&mut self, generation: &Generation<T>) -> bool;
+pub trait Termination<T> {
+    fn reached(&mut self, generation: &Generation<T>) -> bool;
+
+    fn or<O>(self, other: O) -> Any<Self, O> where Self: Sized, O: Termination<T> {
+        Any::new(self, other)
+    }
+
+    fn and<O>(self, other: O) -> All<Self, O> where Self: Sized, O: Termination<T> {
+        All::new(self, other)
+    }
+
+    fn not(self) -> Not<Self> where Self: Sized {
+        Not::new(self)
+    }
+}
+
+impl<'a, T> Termination<T> for Box<Termination<T> + 'a> {
+    fn reached(&mut self, generation: &Generation<T>) -> bool {
+        (**self).reached(generation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use generation::Generation;
+    use objective::Objective;
+    use termination::{EvaluationBudget, FitnessThreshold, Iterations, Termination, TimeLimit};
+
+    #[test]
+    fn combinators_check_every_condition() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let generation = Generation::new(vec![vec![5]], &fitness, Objective::Maximize);
+
+        // The threshold is reached right away, but the iterations still have to be counted.
+        let mut termination = Iterations::new(2).or(FitnessThreshold::new(1.0));
+        assert!(termination.reached(&generation));
+        let mut iterations = Iterations::new(2).and(FitnessThreshold::new(1.0));
+        assert!(!iterations.reached(&generation));
+        assert!(!iterations.reached(&generation));
+        assert!(iterations.reached(&generation));
+    }
+
+    #[test]
+    fn combinators_not() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let generation = Generation::new(vec![vec![5]], &fitness, Objective::Maximize);
+
+        let mut termination = FitnessThreshold::new(10.0).not().and(Iterations::new(0));
+        assert!(termination.reached(&generation));
+
+        let mut iterations = Iterations::new(1).not();
+        assert!(iterations.reached(&generation));
+        for _ in 0..5 {
+            assert!(!iterations.reached(&generation));
+        }
+    }
+
+    #[test]
+    fn combinators_and_after_the_iterations() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let low = Generation::new(vec![vec![5]], &fitness, Objective::Maximize);
+        let high = Generation::new(vec![vec![20]], &fitness, Objective::Maximize);
+
+        // The threshold is only reached long after the iterations ran out.
+        let mut termination = Iterations::new(1).and(FitnessThreshold::new(10.0));
+        for _ in 0..5 {
+            assert!(!termination.reached(&low));
+        }
+        assert!(termination.reached(&high));
+    }
+
+    #[test]
+    fn evaluation_budget() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let generation = Generation::new(vec![vec![1], vec![2], vec![3]], &fitness, Objective::Maximize);
+
+        assert!(generation.evaluations() == 3);
+        assert!(EvaluationBudget::new(3).reached(&generation));
+        assert!(!EvaluationBudget::new(4).reached(&generation));
+    }
+
+    #[test]
+    fn time_limit() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let generation = Generation::new(vec![vec![5]], &fitness, Objective::Maximize);
+
+        assert!(TimeLimit::new(Duration::new(0, 0)).reached(&generation));
+        assert!(!TimeLimit::new(Duration::new(3600, 0)).reached(&generation));
+    }
 }
@@ -0,0 +1,34 @@
+use std::io;
+
+use checkpoint::{Checkpoint, Reader, Writer};
+use generation::Generation;
+use termination::Termination;
+
+// Reached whenever the inner condition is not reached.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Not<A> {
+    inner: A,
+}
+
+impl<A> Not<A> {
+    pub fn new(inner: A) -> Self {
+        Not { inner: inner }
+    }
+}
+
+impl<T, A> Termination<T> for Not<A> where A: Termination<T> {
+    fn reached(&mut self, generation: &Generation<T>) -> bool {
+        !self.inner.reached(generation)
+    }
+}
+
+impl<A> Checkpoint for Not<A> where A: Checkpoint {
+    fn save(&self, writer: &mut Writer) {
+        self.inner.save(writer);
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        self.inner.restore(reader)
+    }
+}
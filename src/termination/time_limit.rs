@@ -0,0 +1,56 @@
+use std::io;
+use std::time::{Duration, Instant};
+
+use checkpoint::{Checkpoint, Reader, Writer, parse};
+use generation::Generation;
+use termination::Termination;
+
+// Reached once the given wall-clock time has passed since the condition was first checked, which is right
+// after the initial population was evaluated. The time spent so far is kept in checkpoints, so a resumed run
+// only gets the remaining time.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimeLimit {
+    limit: Duration,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    start: Option<Instant>,
+}
+
+impl TimeLimit {
+    pub fn new(limit: Duration) -> Self {
+        TimeLimit {
+            limit: limit,
+            start: None,
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        match self.start {
+            Some(start) => start.elapsed(),
+            None => Duration::new(0, 0),
+        }
+    }
+}
+
+impl<T> Termination<T> for TimeLimit {
+    fn reached(&mut self, _: &Generation<T>) -> bool {
+        if self.start.is_none() {
+            self.start = Some(Instant::now());
+        }
+        self.elapsed() >= self.limit
+    }
+}
+
+impl Checkpoint for TimeLimit {
+    fn save(&self, writer: &mut Writer) {
+        let elapsed = self.elapsed();
+        writer.write("time-limit", vec![elapsed.as_secs().to_string(), elapsed.subsec_nanos().to_string()]);
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("time-limit", Some(2)));
+        let elapsed = Duration::new(try!(parse(&values[0])), try!(parse(&values[1])));
+        self.start = Some(Instant::now() - elapsed);
+        Ok(())
+    }
+}
@@ -1,7 +1,12 @@
+use std::io;
+use std::str::FromStr;
+
+use checkpoint::{Checkpoint, Reader, Writer, parse};
 use chromosome::Chromosome;
 use generation::Generation;
 use tracking::Tracking;
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BestSolution<T> {
     best: Option<Chromosome<T>>,
 }
@@ -15,7 +20,7 @@ impl<T> BestSolution<T> where T: Clone {
         if let Some(ref best) = self.best {
             (Vec::from(best.genes()), best.fitness)
         } else {
-            unimplemented!()
+            panic!("No generation has been registered yet");
         }
     }
 }
@@ -23,7 +28,7 @@ impl<T> BestSolution<T> where T: Clone {
 impl<T> Tracking<T> for BestSolution<T> where T: Clone {
     fn register(&mut self, generation: &Generation<T>) {
         if let Some(ref mut best) = self.best {
-            if generation.best().fitness > best.fitness {
+            if generation.objective().is_better(generation.best().fitness, best.fitness) {
                 *best = generation.best().clone();
             }
         } else {
@@ -32,4 +37,41 @@ impl<T> Tracking<T> for BestSolution<T> where T: Clone {
     }
 }
 
+impl<T> Checkpoint for BestSolution<T> where T: ToString + FromStr {
+    fn save(&self, writer: &mut Writer) {
+        if let Some(ref best) = self.best {
+            writer.write("best-solution", vec!["1".to_owned()]);
+            writer.chromosome("chromosome", best);
+        } else {
+            writer.write("best-solution", vec!["0".to_owned()]);
+        }
+    }
 
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("best-solution", Some(1)));
+        let present: usize = try!(parse(&values[0]));
+        self.best = if present == 1 {
+            Some(try!(reader.chromosome("chromosome")))
+        } else {
+            None
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use generation::Generation;
+    use objective::Objective;
+    use tracking::{BestSolution, Tracking};
+
+    #[test]
+    fn best_solution_minimize() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let mut best = BestSolution::new();
+        best.register(&Generation::new(vec![vec![3], vec![2]], &fitness, Objective::Minimize));
+        best.register(&Generation::new(vec![vec![5], vec![4]], &fitness, Objective::Minimize));
+
+        assert!(best.best() == (vec![2], 2.0));
+    }
+}
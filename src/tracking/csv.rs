@@ -0,0 +1,67 @@
+use std::io::{self, Write};
+
+use tracking::{Encode, Export, Format, Record};
+use tracking::export::seconds;
+
+const HEADER: &'static str = "generation,best,worst,mean,median,deviation,hamming_distance,unique_ratio,\
+                              evaluations,elapsed,genes";
+
+// Streams the `Record` of every generation to a CSV file, followed by the genes of the best chromosome
+// (separated by spaces, in a single quoted column). The elapsed time is given in seconds.
+pub type Csv<W> = Export<CsvFormat, W>;
+
+#[derive(Copy, Clone, Default)]
+pub struct CsvFormat;
+
+impl Format for CsvFormat {
+    fn name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn header(&self) -> Option<&'static str> {
+        Some(HEADER)
+    }
+}
+
+impl<T> Encode<T> for CsvFormat where T: ToString {
+    fn encode<W>(&self, writer: &mut W, record: &Record, best: &[T]) -> io::Result<()> where W: Write {
+        let genes = best.iter().map(|g| g.to_string()).collect::<Vec<_>>().join(" ");
+        writeln!(writer, "{},{},{},{},{},{},{},{},{},{},\"{}\"",
+                 record.generation, record.best, record.worst, record.mean, record.median, record.deviation,
+                 record.hamming_distance, record.unique_ratio, record.evaluations, seconds(record.elapsed),
+                 genes.replace('"', "\"\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use generation::Generation;
+    use objective::Objective;
+    use tracking::{Csv, Tracking};
+
+    #[test]
+    fn csv_streams_records() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let mut csv = Csv::new(Vec::new());
+        csv.register(&Generation::new(vec![vec![1, 7], vec![3, 2]], &fitness, Objective::Maximize));
+        csv.register(&Generation::new(vec![vec![4, 5]], &fitness, Objective::Maximize));
+
+        let output = String::from_utf8(csv.finish().unwrap()).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert!(lines.len() == 3);
+        assert!(lines[0].starts_with("generation,best,worst"));
+        assert!(lines[1].starts_with("0,3,1,2,2,1,1,1,2,"));
+        assert!(lines[1].ends_with(",\"3 2\""));
+        assert!(lines[2].starts_with("1,4,4,4,4,0,0,1,1,"));
+    }
+
+    #[test]
+    fn csv_empty_generation() {
+        let mut csv = Csv::new(Vec::new());
+        csv.register(&Generation::<i32>::empty());
+
+        let output = String::from_utf8(csv.finish().unwrap()).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert!(lines[1].starts_with("0,0,0,0,0,0,0,0,0,") && lines[1].ends_with(",\"\""));
+    }
+}
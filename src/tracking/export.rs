@@ -0,0 +1,134 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use checkpoint::{Checkpoint, Reader, Writer, parse};
+use generation::Generation;
+use tracking::{Record, Tracking};
+
+// A line based file format for `Export`.
+pub trait Format {
+    // The key the position of the export is saved under in checkpoints.
+    fn name(&self) -> &'static str;
+
+    // Written once at the start of every new file.
+    fn header(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+pub trait Encode<T>: Format {
+    // Writes a single line for the record of a generation and the genes of its best chromosome.
+    fn encode<W>(&self, writer: &mut W, record: &Record, best: &[T]) -> io::Result<()> where W: Write;
+}
+
+// Streams the `Record` of every generation to a writer in the given format (see `Csv` and `JsonLines`).
+// Every line is flushed as soon as it's written, so the file can be followed while the run is still going.
+//
+// Tracking can't fail, so the first write error is kept and returned by `finish`, and nothing is written
+// after it.
+pub struct Export<F, W> where W: Write {
+    format: F,
+    writer: W,
+    generation: usize,
+    start: Option<Instant>,
+    error: Option<io::Error>,
+}
+
+impl<F, W> Export<F, W> where F: Format + Default, W: Write {
+    // Writes the header of the format right away.
+    pub fn new(writer: W) -> Self {
+        let mut export = Export::headless(writer);
+        if let Some(header) = export.format.header() {
+            let result = writeln!(export.writer, "{}", header);
+            export.check(result);
+        }
+        export
+    }
+
+    fn headless(writer: W) -> Self {
+        Export {
+            format: F::default(),
+            writer: writer,
+            generation: 0,
+            start: None,
+            error: None,
+        }
+    }
+}
+
+impl<F, W> Export<F, W> where W: Write {
+    // Flushes the writer and returns it, unless writing one of the records failed.
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        try!(self.writer.flush());
+        Ok(self.writer)
+    }
+
+    fn check(&mut self, result: io::Result<()>) {
+        if let Err(error) = result {
+            self.error = Some(error);
+        }
+    }
+}
+
+impl<F> Export<F, BufWriter<File>> where F: Format + Default {
+    pub fn create<P>(path: P) -> io::Result<Self> where P: AsRef<Path> {
+        Ok(Export::new(BufWriter::new(try!(File::create(path)))))
+    }
+
+    // Appends to an existing file (for example when resuming from a checkpoint), only writing the header if
+    // the file is empty.
+    pub fn append<P>(path: P) -> io::Result<Self> where P: AsRef<Path> {
+        let file = try!(OpenOptions::new().create(true).append(true).open(path));
+        if try!(file.metadata()).len() == 0 {
+            Ok(Export::new(BufWriter::new(file)))
+        } else {
+            Ok(Export::headless(BufWriter::new(file)))
+        }
+    }
+}
+
+impl<T, F, W> Tracking<T> for Export<F, W> where T: PartialEq, F: Encode<T>, W: Write {
+    fn register(&mut self, generation: &Generation<T>) {
+        if self.start.is_none() {
+            self.start = Some(Instant::now());
+        }
+        let record = Record::new(generation, self.generation, self.start.unwrap().elapsed());
+        self.generation += 1;
+        if self.error.is_some() {
+            return;
+        }
+
+        // An empty generation has no best chromosome, so its genes are left empty.
+        let best = generation.iter().next().map_or(&[][..], |best| best.genes());
+        let result = self.format.encode(&mut self.writer, &record, best)
+            .and_then(|_| self.writer.flush());
+        self.check(result);
+    }
+}
+
+// Only the position in the run is saved, the file itself has to be reopened with `append`.
+impl<F, W> Checkpoint for Export<F, W> where F: Format, W: Write {
+    fn save(&self, writer: &mut Writer) {
+        let elapsed = self.start.map(|start| start.elapsed()).unwrap_or(Duration::new(0, 0));
+        writer.write(self.format.name(), vec![self.generation.to_string(), elapsed.as_secs().to_string(),
+                                              elapsed.subsec_nanos().to_string()]);
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read(self.format.name(), Some(3)));
+        self.generation = try!(parse(&values[0]));
+        let elapsed = Duration::new(try!(parse(&values[1])), try!(parse(&values[2])));
+        self.start = Some(Instant::now() - elapsed);
+        Ok(())
+    }
+}
+
+// The elapsed time of a record in seconds.
+pub fn seconds(elapsed: Duration) -> f64 {
+    elapsed.as_secs() as f64 + elapsed.subsec_nanos() as f64 * 1e-9
+}
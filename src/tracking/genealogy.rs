@@ -0,0 +1,233 @@
+use std::collections::{HashMap, HashSet};
+
+use generation::Generation;
+use objective::Objective;
+use tracking::Tracking;
+
+// How a chromosome came into existence. Operators are identified by their `name`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Origin {
+    // Part of the initial population.
+    Initial,
+    // A child of the crossover, which may have been changed by the mutation afterwards.
+    Crossover { crossover: &'static str, mutation: Option<&'static str> },
+    // A parent that was passed on without a crossover, but changed by the mutation.
+    Mutation { mutation: &'static str },
+    // A copy of a parent that the reinsertion carried over into the next generation (e.g. the elites kept
+    // by `reinsertion::Elitist`). It's credited to the operator named "Elitism".
+    Elitism,
+    // Received from another algorithm, e.g. another island. Its ancestors aren't part of this genealogy.
+    Migration,
+}
+
+// The place of a chromosome in the genealogy, which the algorithm attaches to every new chromosome while a
+// `Genealogy` is tracked. Parents without a lineage of their own are left out of `parents`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lineage {
+    pub id: u64,
+    pub parents: Vec<u64>,
+    pub origin: Origin,
+}
+
+// A chromosome of the genealogy. Parents passed on unchanged keep their id, so `last_generation` tells how
+// long one survived, while copies carried over by the reinsertion get a new one (see `Origin::Elitism`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub id: u64,
+    pub parents: Vec<u64>,
+    pub origin: Origin,
+    pub fitness: f64,
+    pub first_generation: usize,
+    pub last_generation: usize,
+}
+
+// How many chromosomes an operator was involved in, and how many of them were better than all of their
+// parents.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Contribution {
+    pub offspring: usize,
+    pub improvements: usize,
+}
+
+// Records the family tree of every chromosome of the run. Registering this tracker makes the algorithm
+// attach a `Lineage` to every new chromosome, with an id, its parents and the operators that produced it,
+// which takes some extra time and memory. Chromosomes that were restored from a checkpoint don't have a
+// lineage, so they're left out, and migrants start a new lineage.
+pub struct Genealogy {
+    entries: HashMap<u64, Entry>,
+    best: Option<u64>,
+    generation: usize,
+    objective: Objective,
+}
+
+impl Genealogy {
+    pub fn new() -> Self {
+        Genealogy {
+            entries: HashMap::new(),
+            best: None,
+            generation: 0,
+            objective: Objective::default(),
+        }
+    }
+
+    pub fn entry(&self, id: u64) -> Option<&Entry> {
+        self.entries.get(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    // The best chromosome of the whole run.
+    pub fn best(&self) -> Option<&Entry> {
+        self.best.and_then(|id| self.entry(id))
+    }
+
+    // The chromosome with the given id followed by all of its known ancestors, closest ones first.
+    pub fn ancestry(&self, id: u64) -> Vec<&Entry> {
+        let mut ancestry = Vec::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![id];
+        let mut index = 0;
+        while index < pending.len() {
+            let id = pending[index];
+            index += 1;
+            if !visited.insert(id) {
+                continue;
+            }
+            if let Some(entry) = self.entries.get(&id) {
+                pending.extend(entry.parents.iter().cloned());
+                ancestry.push(entry);
+            }
+        }
+        ancestry
+    }
+
+    // The contribution of every crossover and mutation operator by name, and of the reinsertion as
+    // "Elitism".
+    pub fn contributions(&self) -> HashMap<&'static str, Contribution> {
+        let mut contributions = HashMap::new();
+        for entry in self.entries.values() {
+            let operators = match entry.origin {
+                Origin::Crossover { crossover, mutation: Some(mutation) } => vec![crossover, mutation],
+                Origin::Crossover { crossover, mutation: None } => vec![crossover],
+                Origin::Mutation { mutation } => vec![mutation],
+                Origin::Elitism => vec!["Elitism"],
+                Origin::Initial | Origin::Migration => continue,
+            };
+
+            let objective = self.objective;
+            let improved = !entry.parents.is_empty() && entry.parents.iter().all(|id| {
+                match self.entries.get(id) {
+                    Some(parent) => objective.is_better(entry.fitness, parent.fitness),
+                    None => false,
+                }
+            });
+            for operator in operators {
+                let contribution = contributions.entry(operator).or_insert_with(Contribution::default);
+                contribution.offspring += 1;
+                if improved {
+                    contribution.improvements += 1;
+                }
+            }
+        }
+        contributions
+    }
+}
+
+impl<T> Tracking<T> for Genealogy {
+    fn register(&mut self, generation: &Generation<T>) {
+        self.objective = generation.objective();
+        for chromosome in generation.iter() {
+            let lineage = match chromosome.lineage {
+                Some(ref lineage) => lineage,
+                None => continue,
+            };
+            let index = self.generation;
+            let entry = self.entries.entry(lineage.id).or_insert_with(|| Entry {
+                id: lineage.id,
+                parents: lineage.parents.clone(),
+                origin: lineage.origin,
+                fitness: chromosome.fitness,
+                first_generation: index,
+                last_generation: index,
+            });
+            entry.last_generation = index;
+        }
+
+        let best = generation.best();
+        let improved = match self.best() {
+            Some(entry) => self.objective.is_better(best.fitness, entry.fitness),
+            None => true,
+        };
+        if let Some(ref lineage) = best.lineage {
+            if improved {
+                self.best = Some(lineage.id);
+            }
+        }
+        self.generation += 1;
+    }
+
+    fn lineage(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use problem::{Problem, Permutation};
+    use random::Xorshift128;
+    use termination::Iterations;
+    use tracking::{Genealogy, Origin};
+    use utility::weighted_sum;
+
+    #[test]
+    fn migrants_get_a_new_lineage() {
+        let mut rng = Xorshift128::from_seed([1, 2, 3, 4]);
+        let mut islands = (0..2).map(|_| {
+            let mut algorithm = test_algorithm!(&weighted_sum, Genealogy::new());
+            algorithm.initialize(Permutation::from(0..10usize).generate_population(20, &mut rng));
+            algorithm.step();
+            algorithm
+        }).collect::<Vec<_>>();
+
+        let migrants = islands[1].generation().iter().take(5).cloned().collect::<Vec<_>>();
+        islands[0].immigrate(migrants);
+
+        let lineages = islands[0].generation().iter()
+            .map(|c| c.lineage.clone().unwrap())
+            .collect::<Vec<_>>();
+        let mut ids = lineages.iter().map(|lineage| lineage.id).collect::<Vec<_>>();
+        ids.sort();
+        ids.dedup();
+        assert!(ids.len() == lineages.len());
+        let migrated = lineages.iter()
+            .filter(|lineage| lineage.origin == Origin::Migration)
+            .collect::<Vec<_>>();
+        assert!(migrated.len() == 5);
+        assert!(migrated.iter().all(|lineage| lineage.parents.is_empty()));
+    }
+
+    #[test]
+    fn genealogy_reaches_the_initial_population() {
+        let mut rng = Xorshift128::from_seed([1, 2, 3, 4]);
+        let population = Permutation::from(0..10usize).generate_population(20, &mut rng);
+        let mut algorithm = test_algorithm!(&weighted_sum, Genealogy::new());
+
+        let genealogy = algorithm.evolve(population, Iterations::new(10));
+        let best = genealogy.best().unwrap();
+        let ancestry = genealogy.ancestry(best.id);
+        assert!(ancestry[0] == best);
+        for entry in ancestry {
+            assert!(entry.parents.is_empty() == (entry.origin == Origin::Initial));
+        }
+
+        let contributions = genealogy.contributions();
+        assert!(contributions["Order1"].offspring > 0);
+        assert!(contributions["Elitism"].offspring > 0);
+        assert!(contributions["Elitism"].improvements == 0);
+        assert!(contributions.keys().all(|&name| ["Order1", "Twors", "Elitism"].contains(&name)));
+    }
+}
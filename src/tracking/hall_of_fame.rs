@@ -0,0 +1,130 @@
+use std::io;
+use std::str::FromStr;
+
+use checkpoint::{Checkpoint, Reader, Writer, parse};
+use chromosome::Chromosome;
+use diversity;
+use generation::Generation;
+use tracking::Tracking;
+
+// Keeps the best distinct chromosomes seen during the whole run, up to the given size. By default only
+// chromosomes with identical genes are considered the same. With `min_distance` a chromosome is also
+// considered the same as every member whose genes differ in at most that fraction of positions (see
+// `diversity::distance`), and only the better one of them is kept.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HallOfFame<T> {
+    size: usize,
+    min_distance: f64,
+    members: Vec<Chromosome<T>>,
+}
+
+impl<T> HallOfFame<T> where T: Clone + PartialEq {
+    pub fn new(size: usize) -> Self {
+        if size == 0 {
+            panic!("A hall of fame has to keep at least one chromosome");
+        }
+        HallOfFame {
+            size: size,
+            min_distance: 0.0,
+            members: Vec::new(),
+        }
+    }
+
+    pub fn min_distance(mut self, min_distance: f64) -> Self {
+        self.min_distance = min_distance;
+        self
+    }
+
+    // The genes and fitness of every member, the best one first. Empty until a generation was registered.
+    pub fn members(&self) -> Vec<(Vec<T>, f64)> {
+        self.members.iter()
+            .map(|c| (Vec::from(c.genes()), c.fitness))
+            .collect()
+    }
+
+    fn insert(&mut self, chromosome: &Chromosome<T>, generation: &Generation<T>) {
+        let objective = generation.objective();
+        let similar = self.members.iter()
+            .filter(|m| diversity::distance(m.genes(), chromosome.genes()) <= self.min_distance)
+            .map(|m| m.fitness)
+            .collect::<Vec<_>>();
+        if similar.iter().any(|&fitness| !objective.is_better(chromosome.fitness, fitness)) {
+            return;
+        }
+
+        let min_distance = self.min_distance;
+        self.members.retain(|m| diversity::distance(m.genes(), chromosome.genes()) > min_distance);
+        let position = self.members.iter()
+            .position(|m| objective.is_better(chromosome.fitness, m.fitness))
+            .unwrap_or(self.members.len());
+        self.members.insert(position, chromosome.clone());
+        self.members.truncate(self.size);
+    }
+}
+
+impl<T> Tracking<T> for HallOfFame<T> where T: Clone + PartialEq {
+    fn register(&mut self, generation: &Generation<T>) {
+        let objective = generation.objective();
+        for chromosome in generation.iter() {
+            // The generation is sorted, so none of the remaining chromosomes can make it in either.
+            let full = self.members.len() == self.size;
+            if full && !objective.is_better(chromosome.fitness, self.members[self.size - 1].fitness) {
+                break;
+            }
+            self.insert(chromosome, generation);
+        }
+    }
+}
+
+impl<T> Checkpoint for HallOfFame<T> where T: ToString + FromStr {
+    fn save(&self, writer: &mut Writer) {
+        writer.write("hall-of-fame", vec![self.members.len().to_string()]);
+        for chromosome in &self.members {
+            writer.chromosome("chromosome", chromosome);
+        }
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("hall-of-fame", Some(1)));
+        let size: usize = try!(parse(&values[0]));
+
+        self.members.clear();
+        for _ in 0..size {
+            self.members.push(try!(reader.chromosome("chromosome")));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use generation::Generation;
+    use objective::Objective;
+    use tracking::{HallOfFame, Tracking};
+
+    #[test]
+    fn hall_of_fame_keeps_distinct_chromosomes() {
+        let fitness = |genes: &[i32]| genes.iter().sum::<i32>() as f64;
+        let mut hall = HallOfFame::new(3);
+
+        let first = vec![vec![5, 5], vec![5, 5], vec![1, 1]];
+        hall.register(&Generation::new(first, &fitness, Objective::Maximize));
+        let second = vec![vec![4, 4], vec![5, 5], vec![0, 0]];
+        hall.register(&Generation::new(second, &fitness, Objective::Maximize));
+
+        let fitnesses = hall.members().iter().map(|m| m.1).collect::<Vec<_>>();
+        assert!(fitnesses == vec![10.0, 8.0, 2.0]);
+    }
+
+    #[test]
+    fn hall_of_fame_min_distance() {
+        let fitness = |genes: &[i32]| genes.iter().sum::<i32>() as f64;
+        let mut hall = HallOfFame::new(3).min_distance(0.5);
+        let population = vec![vec![1, 2, 3, 4], vec![1, 2, 3, 3], vec![1, 2, 0, 0], vec![9, 9, 9, 9]];
+        hall.register(&Generation::new(population, &fitness, Objective::Maximize));
+
+        // [1, 2, 3, 3] and [1, 2, 0, 0] are too similar to the better [1, 2, 3, 4].
+        let members = hall.members().into_iter().map(|m| m.0).collect::<Vec<_>>();
+        assert!(members == vec![vec![9, 9, 9, 9], vec![1, 2, 3, 4]]);
+    }
+}
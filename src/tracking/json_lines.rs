@@ -0,0 +1,79 @@
+use std::io::{self, Write};
+
+use serde::Serialize;
+use serde_json;
+
+use tracking::{Encode, Export, Format, Record};
+use tracking::export::seconds;
+
+#[derive(Serialize)]
+struct Line<'a, T> where T: 'a {
+    generation: usize,
+    best: f64,
+    worst: f64,
+    mean: f64,
+    median: f64,
+    deviation: f64,
+    hamming_distance: f64,
+    unique_ratio: f64,
+    evaluations: usize,
+    elapsed: f64,
+    genes: &'a [T],
+}
+
+// Streams the `Record` of every generation as one JSON object per line, together with the genes of the
+// best chromosome. The keys are the field names of `Record`, and the elapsed time is given in seconds.
+pub type JsonLines<W> = Export<JsonLinesFormat, W>;
+
+#[derive(Copy, Clone, Default)]
+pub struct JsonLinesFormat;
+
+impl Format for JsonLinesFormat {
+    fn name(&self) -> &'static str {
+        "json-lines"
+    }
+}
+
+impl<T> Encode<T> for JsonLinesFormat where T: Serialize {
+    fn encode<W>(&self, writer: &mut W, record: &Record, best: &[T]) -> io::Result<()> where W: Write {
+        let line = Line {
+            generation: record.generation,
+            best: record.best,
+            worst: record.worst,
+            mean: record.mean,
+            median: record.median,
+            deviation: record.deviation,
+            hamming_distance: record.hamming_distance,
+            unique_ratio: record.unique_ratio,
+            evaluations: record.evaluations,
+            elapsed: seconds(record.elapsed),
+            genes: best,
+        };
+        try!(serde_json::to_writer(&mut *writer, &line).map_err(io::Error::from));
+        writeln!(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+
+    use generation::Generation;
+    use objective::Objective;
+    use tracking::{JsonLines, Tracking};
+
+    #[test]
+    fn json_lines_streams_records() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let mut json = JsonLines::new(Vec::new());
+        json.register(&Generation::new(vec![vec![1, 7], vec![3, 2]], &fitness, Objective::Maximize));
+        json.register(&Generation::new(vec![vec![4, 5]], &fitness, Objective::Maximize));
+
+        let output = String::from_utf8(json.finish().unwrap()).unwrap();
+        let lines = output.lines().map(|l| serde_json::from_str::<Value>(l).unwrap()).collect::<Vec<_>>();
+        assert!(lines.len() == 2);
+        assert!(lines[0]["generation"] == 0 && lines[0]["best"] == 3.0 && lines[0]["evaluations"] == 2);
+        assert!(lines[0]["genes"][0] == 3 && lines[0]["genes"][1] == 2);
+        assert!(lines[1]["worst"] == 4.0);
+    }
+}
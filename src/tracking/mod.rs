@@ -1,10 +1,56 @@
 mod best_solution;
+mod csv;
+mod export;
+mod genealogy;
+mod hall_of_fame;
+#[cfg(feature = "serde")]
+mod json_lines;
+mod pareto_front;
+mod statistics;
+
+use std::any::Any;
 
 use generation::Generation;
 
 pub use self::best_solution::BestSolution;
+pub use self::csv::{Csv, CsvFormat};
+pub use self::export::{Encode, Export, Format};
+pub use self::genealogy::{Contribution, Entry, Genealogy, Lineage, Origin};
+pub use self::hall_of_fame::HallOfFame;
+#[cfg(feature = "serde")]
+pub use self::json_lines::{JsonLines, JsonLinesFormat};
+pub use self::pareto_front::ParetoFront;
+pub use self::statistics::{Record, Statistics};
 
 pub trait Tracking<T> {
     fn register(&mut self, generation: &Generation<T>);
+
+    // Whether the algorithm should attach a `Lineage` to every new chromosome.
+    fn lineage(&self) -> bool {
+        false
+    }
+}
+
+// Boxed trackers are used when the tracker is chosen at runtime. The concrete tracker can be recovered
+// through `as_any`, e.g. `(**tracking).as_any().downcast_ref::<BestSolution<usize>>()`. Note that the box
+// has to be dereferenced, since the box itself is a tracker as well.
+pub trait DynTracking<T>: Tracking<T> {
+    fn as_any(&self) -> &Any;
+}
+
+impl<T, TR> DynTracking<T> for TR where TR: Tracking<T> + Any {
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+impl<'a, T> Tracking<T> for Box<DynTracking<T> + 'a> {
+    fn register(&mut self, generation: &Generation<T>) {
+        (**self).register(generation)
+    }
+
+    fn lineage(&self) -> bool {
+        (**self).lineage()
+    }
 }
 
@@ -0,0 +1,61 @@
+use std::io;
+use std::str::FromStr;
+
+use checkpoint::{Checkpoint, Reader, Writer, parse};
+use chromosome::Chromosome;
+use generation::Generation;
+use tracking::Tracking;
+
+// Keeps the first Pareto front of the latest generation, for use with `pareto::Pareto`. Duplicate genomes
+// are only kept once.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParetoFront<T> {
+    front: Vec<Chromosome<T>>,
+}
+
+impl<T> ParetoFront<T> where T: Clone + PartialEq {
+    pub fn new() -> Self {
+        ParetoFront { front: Vec::new() }
+    }
+
+    pub fn front(&self) -> Vec<(Vec<T>, Vec<f64>)> {
+        self.front.iter()
+            .map(|c| {
+                let objectives = c.pareto.as_ref().map_or(Vec::new(), |rank| rank.objectives.clone());
+                (Vec::from(c.genes()), objectives)
+            })
+            .collect()
+    }
+}
+
+impl<T> Tracking<T> for ParetoFront<T> where T: Clone + PartialEq {
+    fn register(&mut self, generation: &Generation<T>) {
+        self.front.clear();
+        let first = generation.iter().filter(|c| c.pareto.as_ref().map_or(false, |rank| rank.front == 0));
+        for chromosome in first {
+            if !self.front.iter().any(|c| c.genes() == chromosome.genes()) {
+                self.front.push(chromosome.clone());
+            }
+        }
+    }
+}
+
+impl<T> Checkpoint for ParetoFront<T> where T: ToString + FromStr {
+    fn save(&self, writer: &mut Writer) {
+        writer.write("pareto-front", vec![self.front.len().to_string()]);
+        for chromosome in &self.front {
+            writer.chromosome("chromosome", chromosome);
+        }
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("pareto-front", Some(1)));
+        let size: usize = try!(parse(&values[0]));
+
+        self.front.clear();
+        for _ in 0..size {
+            self.front.push(try!(reader.chromosome("chromosome")));
+        }
+        Ok(())
+    }
+}
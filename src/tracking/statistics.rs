@@ -0,0 +1,189 @@
+use std::io;
+use std::time::{Duration, Instant};
+
+use checkpoint::{Checkpoint, Reader, Writer, parse};
+use diversity;
+use generation::Generation;
+use tracking::Tracking;
+
+// The metrics of a single generation. `best` and `worst` follow the objective of the generation, so when
+// minimizing `best` is the lowest fitness.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Record {
+    pub generation: usize,
+    pub best: f64,
+    pub worst: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub deviation: f64,
+    pub hamming_distance: f64,
+    pub unique_ratio: f64,
+    pub evaluations: usize,
+    pub elapsed: Duration,
+}
+
+impl Record {
+    // Computes the record of a generation, given its index in the run and the time elapsed since the run
+    // started. Like the diversity measures, every metric of an empty generation is 0.
+    pub fn new<T>(generation: &Generation<T>, index: usize, elapsed: Duration) -> Self where T: PartialEq {
+        let size = generation.size();
+        if size == 0 {
+            return Record {
+                generation: index,
+                best: 0.0,
+                worst: 0.0,
+                mean: 0.0,
+                median: 0.0,
+                deviation: 0.0,
+                hamming_distance: 0.0,
+                unique_ratio: 0.0,
+                evaluations: generation.evaluations(),
+                elapsed: elapsed,
+            };
+        }
+
+        // The generation is sorted from best to worst, so the median is in the middle either way.
+        let median = if size % 2 == 0 {
+            (generation[size / 2 - 1].fitness + generation[size / 2].fitness) / 2.0
+        } else {
+            generation[size / 2].fitness
+        };
+
+        Record {
+            generation: index,
+            best: generation.best().fitness,
+            worst: generation[size - 1].fitness,
+            mean: generation.total_fitness() / size as f64,
+            median: median,
+            deviation: diversity::fitness_deviation(generation),
+            hamming_distance: diversity::hamming_distance(generation),
+            unique_ratio: diversity::unique_ratio(generation),
+            evaluations: generation.evaluations(),
+            elapsed: elapsed,
+        }
+    }
+}
+
+// Records the fitness statistics and diversity of every generation, starting with the initial population
+// (generation 0). The elapsed time is measured from the registration of the initial population.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Statistics {
+    records: Vec<Record>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    start: Option<Instant>,
+}
+
+impl Statistics {
+    pub fn new() -> Self {
+        Statistics {
+            records: Vec::new(),
+            start: None,
+        }
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    pub fn last(&self) -> Option<&Record> {
+        self.records.last()
+    }
+
+    // A single metric over all generations, e.g. `statistics.series(|r| r.mean)`, ready to be plotted.
+    pub fn series<F>(&self, metric: F) -> Vec<f64> where F: Fn(&Record) -> f64 {
+        self.records.iter().map(metric).collect()
+    }
+}
+
+impl<T> Tracking<T> for Statistics where T: PartialEq {
+    fn register(&mut self, generation: &Generation<T>) {
+        if self.start.is_none() {
+            self.start = Some(Instant::now());
+        }
+        let start = self.start.unwrap();
+        let record = Record::new(generation, self.records.len(), start.elapsed());
+        self.records.push(record);
+    }
+}
+
+impl Checkpoint for Statistics {
+    fn save(&self, writer: &mut Writer) {
+        let elapsed = self.start.map(|start| start.elapsed()).unwrap_or(Duration::new(0, 0));
+        writer.write("statistics", vec![self.records.len().to_string(), elapsed.as_secs().to_string(),
+                                        elapsed.subsec_nanos().to_string()]);
+        for record in &self.records {
+            writer.write("record", vec![record.best.to_string(),
+                                        record.worst.to_string(),
+                                        record.mean.to_string(),
+                                        record.median.to_string(),
+                                        record.deviation.to_string(),
+                                        record.hamming_distance.to_string(),
+                                        record.unique_ratio.to_string(),
+                                        record.evaluations.to_string(),
+                                        record.elapsed.as_secs().to_string(),
+                                        record.elapsed.subsec_nanos().to_string()]);
+        }
+    }
+
+    fn restore(&mut self, reader: &mut Reader) -> io::Result<()> {
+        let values = try!(reader.read("statistics", Some(3)));
+        let size: usize = try!(parse(&values[0]));
+        let elapsed = Duration::new(try!(parse(&values[1])), try!(parse(&values[2])));
+
+        self.records.clear();
+        for generation in 0..size {
+            let values = try!(reader.read("record", Some(10)));
+            self.records.push(Record {
+                generation: generation,
+                best: try!(parse(&values[0])),
+                worst: try!(parse(&values[1])),
+                mean: try!(parse(&values[2])),
+                median: try!(parse(&values[3])),
+                deviation: try!(parse(&values[4])),
+                hamming_distance: try!(parse(&values[5])),
+                unique_ratio: try!(parse(&values[6])),
+                evaluations: try!(parse(&values[7])),
+                elapsed: Duration::new(try!(parse(&values[8])), try!(parse(&values[9]))),
+            });
+        }
+        self.start = Some(Instant::now() - elapsed);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use generation::Generation;
+    use objective::Objective;
+    use tracking::{Statistics, Tracking};
+
+    #[test]
+    fn statistics_records_every_generation() {
+        let fitness = |genes: &[i32]| genes[0] as f64;
+        let mut statistics = Statistics::new();
+
+        let first = Generation::new(vec![vec![1], vec![2], vec![3], vec![6]], &fitness, Objective::Minimize);
+        statistics.register(&first);
+        let second = Generation::new(vec![vec![4], vec![4], vec![4]], &fitness, Objective::Minimize);
+        statistics.register(&second);
+
+        let records = statistics.records();
+        assert!(records.len() == 2);
+        assert!(records[0].best == 1.0 && records[0].worst == 6.0);
+        assert!(records[0].mean == 3.0 && records[0].median == 2.5);
+        assert!(records[0].unique_ratio == 1.0);
+        assert!(records[1].generation == 1);
+        assert!(records[1].deviation == 0.0 && records[1].hamming_distance == 0.0);
+        assert!(statistics.series(|r| r.median) == vec![2.5, 4.0]);
+    }
+
+    #[test]
+    fn statistics_empty_generation() {
+        let mut statistics = Statistics::new();
+        statistics.register(&Generation::<i32>::empty());
+
+        let record = statistics.last().unwrap();
+        assert!(record.best == 0.0 && record.mean == 0.0 && record.median == 0.0);
+    }
+}
@@ -4,8 +4,10 @@ macro_rules! assert_approx_eq {
     }};
 }
 
+// The optional trailing identifiers name methods that are called whenever the forwarded field is
+// borrowed mutably.
 macro_rules! forward_as {
-    ($Name: ident, $Type: ty, $field: ident) => {
+    ($Name: ident, $Type: ty, $field: ident $(, $hook: ident)*) => {
         impl<T> AsRef<[$Type]> for $Name<T> {
             fn as_ref(&self) -> &[$Type] {
                 &self.$field
@@ -20,6 +22,7 @@ macro_rules! forward_as {
 
         impl<T> AsMut<[$Type]> for $Name<T> {
             fn as_mut(&mut self) -> &mut [$Type] {
+                $(self.$hook();)*
                 &mut self.$field
             }
         }
@@ -40,6 +43,7 @@ macro_rules! forward_as {
 
         impl<T> ::std::ops::DerefMut for $Name<T> {
             fn deref_mut(&mut self) -> &mut [$Type] {
+                $(self.$hook();)*
                 &mut self.$field
             }
         }
@@ -47,7 +51,7 @@ macro_rules! forward_as {
 }
 
 macro_rules! forward_index {
-    ($Name: ident, $field: ident, Index($Index: ty, $Out: ty)) => {
+    ($Name: ident, $field: ident, Index($Index: ty, $Out: ty) $(, $hook: ident)*) => {
         impl<T> ::std::ops::Index<$Index> for $Name<T> {
             type Output = $Out;
 
@@ -58,22 +62,23 @@ macro_rules! forward_index {
 
         impl<T> ::std::ops::IndexMut<$Index> for $Name<T> {
             fn index_mut(&mut self, index: $Index) -> &mut $Out {
+                $(self.$hook();)*
                 &mut self.$field[index]
             }
         }
     };
 
-    ($Name: ident, $Type: ty, $field: ident) => {
-        forward_index!($Name, $field, Index(usize, $Type));
-        forward_index!($Name, $field, Index(::std::ops::Range<usize>, [$Type]));
-        forward_index!($Name, $field, Index(::std::ops::RangeFrom<usize>, [$Type]));
-        forward_index!($Name, $field, Index(::std::ops::RangeTo<usize>, [$Type]));
-        forward_index!($Name, $field, Index(::std::ops::RangeFull, [$Type]));
+    ($Name: ident, $Type: ty, $field: ident $(, $hook: ident)*) => {
+        forward_index!($Name, $field, Index(usize, $Type) $(, $hook)*);
+        forward_index!($Name, $field, Index(::std::ops::Range<usize>, [$Type]) $(, $hook)*);
+        forward_index!($Name, $field, Index(::std::ops::RangeFrom<usize>, [$Type]) $(, $hook)*);
+        forward_index!($Name, $field, Index(::std::ops::RangeTo<usize>, [$Type]) $(, $hook)*);
+        forward_index!($Name, $field, Index(::std::ops::RangeFull, [$Type]) $(, $hook)*);
     };
 }
 
 macro_rules! forward_into_iter {
-    ($Name: ident, $Type: ty, $field: ident) => {
+    ($Name: ident, $Type: ty, $field: ident $(, $hook: ident)*) => {
         impl<T> IntoIterator for $Name<T> {
             type Item = $Type;
             type IntoIter = ::std::vec::IntoIter<$Type>;
@@ -97,6 +102,7 @@ macro_rules! forward_into_iter {
             type IntoIter = ::std::slice::IterMut<'a, $Type>;
 
             fn into_iter(self) -> ::std::slice::IterMut<'a, $Type> {
+                $(self.$hook();)*
                 self.$field.iter_mut()
             }
         }
@@ -122,3 +128,34 @@ macro_rules! unique_samples {
         unique_samples!($from, $to, ::rand::thread_rng())
     }};
 }
+
+// Implements `Checkpoint` for operators without any state, which have nothing to save.
+macro_rules! stateless_checkpoint {
+    ($($Name: ident),*) => {
+        $(
+            impl ::checkpoint::Checkpoint for $Name {
+                fn save(&self, _: &mut ::checkpoint::Writer) {}
+
+                fn restore(&mut self, _: &mut ::checkpoint::Reader) -> ::std::io::Result<()> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+// The algorithm most tests evolve: a tournament selection, the order 1 crossover, the twors mutation and an
+// elitist reinsertion. Tests of another selection or reinsertion can pass their own.
+#[cfg(test)]
+macro_rules! test_algorithm {
+    ($fitness: expr, $selection: expr, $reinsertion: expr, $tracking: expr) => {
+        ::algorithm::Algorithm::new($fitness, $selection, (::crossover::Order1::new(), 0.8),
+                                    (::mutation::Twors::new(), 0.2), $reinsertion, $tracking)
+    };
+    ($fitness: expr, $selection: expr, $tracking: expr) => {
+        test_algorithm!($fitness, $selection, ::reinsertion::Elitist::new(), $tracking)
+    };
+    ($fitness: expr, $tracking: expr) => {
+        test_algorithm!($fitness, ::selection::Tournament::new(0.9, 3), $tracking)
+    };
+}
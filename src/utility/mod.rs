@@ -18,6 +18,16 @@ pub trait Gather: Iterator where Self: Sized {
 
 impl<I> Gather for I where I: Iterator {}
 
+// The name of a type without its module path, e.g. `Order1` instead of `genetic::crossover::Order1`.
+pub fn short_type_name<T>() -> &'static str where T: ?Sized {
+    let name = ::std::any::type_name::<T>();
+    let end = name.find('<').unwrap_or(name.len());
+    match name[..end].rfind("::") {
+        Some(start) => &name[start + 2..end],
+        None => &name[..end],
+    }
+}
+
 pub trait RngExt: Rng where Self: Sized {
     fn choose1<'a, T>(&mut self, slice: &'a [T]) -> &'a T {
         self.choose(slice)
@@ -43,3 +53,22 @@ pub trait RngExt: Rng where Self: Sized {
 }
 
 impl<R> RngExt for R where R: RngExt {}
+
+// A fitness function for permutation problems, which is maximized by sorting the genes in ascending order.
+#[cfg(test)]
+pub fn weighted_sum(genes: &[usize]) -> f64 {
+    genes.iter().enumerate().fold(0.0, |sum, (i, g)| sum + (i * g) as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use mutation::UniformMutation;
+    use problem::Numeric;
+    use utility::short_type_name;
+
+    #[test]
+    fn short_type_name_drops_paths_and_parameters() {
+        assert!(short_type_name::<UniformMutation<i32, Numeric<i32>>>() == "UniformMutation");
+        assert!(short_type_name::<u8>() == "u8");
+    }
+}